@@ -0,0 +1,111 @@
+/* porcus: pig latin for the whole Latin script. */
+
+#ifndef PORCUS_H
+#define PORCUS_H
+
+/* Generated with cbindgen from src/lib.rs, do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Result of a call.
+typedef enum PorcusStatus {
+  // The call succeeded.
+  PORCUS_STATUS_OK = 0,
+  // A required pointer was null.
+  PORCUS_STATUS_NULL_POINTER = 1,
+  // A string was not valid UTF-8.
+  PORCUS_STATUS_INVALID_UTF8 = 2,
+  // The translation failed unexpectedly. This is a bug.
+  PORCUS_STATUS_PANIC = 3,
+} PorcusStatus;
+
+// Pig latin transformer, with its suffixes.
+typedef struct PorcusTransformer PorcusTransformer;
+
+// UTF-8 string owned by the caller.
+//
+// The bytes are followed by a NUL, so that `data` can be used as a C string if the text has no
+// NUL of its own.
+typedef struct PorcusString {
+  // Bytes of the string, or null if there is no string.
+  char *data;
+  // Number of bytes, without the final NUL.
+  size_t length;
+} PorcusString;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Gets a description of a status, as a static NUL-terminated string.
+//
+// Any value is accepted, so that a status can be reported even if it is not one of
+// [`PorcusStatus`].
+const char *porcus_status_message(int status);
+
+// Creates a transformer with the default suffixes, `ay` and `way`.
+//
+// The transformer must be freed with [`porcus_transformer_free`].
+struct PorcusTransformer *porcus_transformer_new(void);
+
+// Frees a transformer. Does nothing if it is null.
+//
+// # Safety
+//
+// The transformer must be null, or created by [`porcus_transformer_new`] and not freed yet.
+void porcus_transformer_free(struct PorcusTransformer *transformer);
+
+// Sets the suffix appended to words starting with a consonant.
+//
+// The transformer is left unchanged if an error is returned.
+//
+// # Safety
+//
+// The transformer must be null, or valid and not used by another thread. The suffix must be
+// null, or point to `length` readable bytes.
+enum PorcusStatus porcus_transformer_set_consonant_suffix(struct PorcusTransformer *transformer,
+                                                          const char *suffix,
+                                                          size_t length);
+
+// Sets the suffix appended to words starting with a vowel.
+//
+// The transformer is left unchanged if an error is returned.
+//
+// # Safety
+//
+// The transformer must be null, or valid and not used by another thread. The suffix must be
+// null, or point to `length` readable bytes.
+enum PorcusStatus porcus_transformer_set_vowel_suffix(struct PorcusTransformer *transformer,
+                                                      const char *suffix,
+                                                      size_t length);
+
+// Translates UTF-8 text to pig latin.
+//
+// On success, the translation is written to `output` and must be freed with
+// [`porcus_string_free`]. On error, `output` is set to a null string.
+//
+// # Safety
+//
+// The transformer must be null or valid. The text must be null, or point to `length` readable
+// bytes. The output must be null, or point to a writable `PorcusString`.
+enum PorcusStatus porcus_transform(const struct PorcusTransformer *transformer,
+                                   const char *text,
+                                   size_t length,
+                                   struct PorcusString *output);
+
+// Frees a string returned by porcus. Does nothing if it is null.
+//
+// # Safety
+//
+// The string must be null, or returned by porcus and not freed yet.
+void porcus_string_free(struct PorcusString string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PORCUS_H */
@@ -0,0 +1,358 @@
+//! C bindings for [porcus](https://github.com/LeopoldTal/porcus/), pig latin for the whole Latin
+//! script.
+//!
+//! The functions are declared in `include/porcus.h`. Strings are passed as UTF-8 bytes and their
+//! length, without a terminating NUL. Translations are returned in buffers owned by the caller,
+//! who frees them with [`porcus_string_free`].
+//!
+//! ```c
+//! PorcusTransformer *transformer = porcus_transformer_new();
+//! PorcusString pig;
+//! if (porcus_transform(transformer, "Pig latin", 9, &pig) == PORCUS_STATUS_OK) {
+//!     puts(pig.data);
+//!     porcus_string_free(pig);
+//! }
+//! porcus_transformer_free(transformer);
+//! ```
+//!
+//! A transformer may be used from several threads at once, as long as it is not changed or freed
+//! meanwhile.
+
+use porcus::PigLatinTransformer;
+use std::ffi::{c_char, c_int, CStr};
+use std::panic::{self, AssertUnwindSafe};
+use std::{mem, ptr, slice, str};
+
+/// Result of a call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PorcusStatus {
+	/// The call succeeded.
+	Ok = 0,
+	/// A required pointer was null.
+	NullPointer = 1,
+	/// A string was not valid UTF-8.
+	InvalidUtf8 = 2,
+	/// The translation failed unexpectedly. This is a bug.
+	Panic = 3,
+}
+
+/// Pig latin transformer, with its suffixes.
+pub struct PorcusTransformer {
+	transformer: PigLatinTransformer,
+}
+
+/// UTF-8 string owned by the caller.
+///
+/// The bytes are followed by a NUL, so that `data` can be used as a C string if the text has no
+/// NUL of its own.
+#[repr(C)]
+#[derive(Debug)]
+pub struct PorcusString {
+	/// Bytes of the string, or null if there is no string.
+	pub data: *mut c_char,
+	/// Number of bytes, without the final NUL.
+	pub length: usize,
+}
+
+impl PorcusString {
+	const fn null() -> Self {
+		Self {
+			data: ptr::null_mut(),
+			length: 0,
+		}
+	}
+
+	fn new(s: String) -> Self {
+		let mut bytes = s.into_bytes();
+		let length = bytes.len();
+		bytes.push(0);
+		Self {
+			data: Box::into_raw(bytes.into_boxed_slice()).cast::<c_char>(),
+			length,
+		}
+	}
+}
+
+impl PorcusStatus {
+	/// Gets the status with a value, if there is one.
+	const fn from_raw(status: c_int) -> Option<Self> {
+		match status {
+			0 => Some(Self::Ok),
+			1 => Some(Self::NullPointer),
+			2 => Some(Self::InvalidUtf8),
+			3 => Some(Self::Panic),
+			_ => None,
+		}
+	}
+}
+
+/// Gets a description of a status, as a static NUL-terminated string.
+///
+/// Any value is accepted, so that a status can be reported even if it is not one of
+/// [`PorcusStatus`].
+#[no_mangle]
+pub extern "C" fn porcus_status_message(status: c_int) -> *const c_char {
+	let message: &CStr = match PorcusStatus::from_raw(status) {
+		Some(PorcusStatus::Ok) => c"success",
+		Some(PorcusStatus::NullPointer) => c"null pointer",
+		Some(PorcusStatus::InvalidUtf8) => c"invalid UTF-8",
+		Some(PorcusStatus::Panic) => c"internal error",
+		None => c"unknown status",
+	};
+	message.as_ptr()
+}
+
+/// Creates a transformer with the default suffixes, `ay` and `way`.
+///
+/// The transformer must be freed with [`porcus_transformer_free`].
+#[no_mangle]
+pub extern "C" fn porcus_transformer_new() -> *mut PorcusTransformer {
+	Box::into_raw(Box::new(PorcusTransformer {
+		transformer: PigLatinTransformer::default(),
+	}))
+}
+
+/// Frees a transformer. Does nothing if it is null.
+///
+/// # Safety
+///
+/// The transformer must be null, or created by [`porcus_transformer_new`] and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn porcus_transformer_free(transformer: *mut PorcusTransformer) {
+	if !transformer.is_null() {
+		drop(Box::from_raw(transformer));
+	}
+}
+
+/// Sets the suffix appended to words starting with a consonant.
+///
+/// The transformer is left unchanged if an error is returned.
+///
+/// # Safety
+///
+/// The transformer must be null, or valid and not used by another thread. The suffix must be
+/// null, or point to `length` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn porcus_transformer_set_consonant_suffix(
+	transformer: *mut PorcusTransformer,
+	suffix: *const c_char,
+	length: usize,
+) -> PorcusStatus {
+	set_suffix(transformer, suffix, length, |transformer, suffix| {
+		transformer.with_consonant_suffix(suffix)
+	})
+}
+
+/// Sets the suffix appended to words starting with a vowel.
+///
+/// The transformer is left unchanged if an error is returned.
+///
+/// # Safety
+///
+/// The transformer must be null, or valid and not used by another thread. The suffix must be
+/// null, or point to `length` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn porcus_transformer_set_vowel_suffix(
+	transformer: *mut PorcusTransformer,
+	suffix: *const c_char,
+	length: usize,
+) -> PorcusStatus {
+	set_suffix(transformer, suffix, length, |transformer, suffix| {
+		transformer.with_vowel_suffix(suffix)
+	})
+}
+
+/// Translates UTF-8 text to pig latin.
+///
+/// On success, the translation is written to `output` and must be freed with
+/// [`porcus_string_free`]. On error, `output` is set to a null string.
+///
+/// # Safety
+///
+/// The transformer must be null or valid. The text must be null, or point to `length` readable
+/// bytes. The output must be null, or point to a writable `PorcusString`.
+#[no_mangle]
+pub unsafe extern "C" fn porcus_transform(
+	transformer: *const PorcusTransformer,
+	text: *const c_char,
+	length: usize,
+	output: *mut PorcusString,
+) -> PorcusStatus {
+	let Some(output) = output.as_mut() else {
+		return PorcusStatus::NullPointer;
+	};
+	*output = PorcusString::null();
+	let Some(transformer) = transformer.as_ref() else {
+		return PorcusStatus::NullPointer;
+	};
+	let text = match borrow_str(text, length) {
+		Ok(text) => text,
+		Err(status) => return status,
+	};
+	match panic::catch_unwind(AssertUnwindSafe(|| {
+		transformer.transformer.to_pig_latin(text)
+	})) {
+		Ok(pig) => {
+			*output = PorcusString::new(pig);
+			PorcusStatus::Ok
+		}
+		Err(_) => PorcusStatus::Panic,
+	}
+}
+
+/// Frees a string returned by porcus. Does nothing if it is null.
+///
+/// # Safety
+///
+/// The string must be null, or returned by porcus and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn porcus_string_free(string: PorcusString) {
+	if !string.data.is_null() {
+		drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+			string.data.cast::<u8>(),
+			string.length + 1,
+		)));
+	}
+}
+
+/// Borrows a UTF-8 string passed as a pointer and a length.
+unsafe fn borrow_str<'a>(s: *const c_char, length: usize) -> Result<&'a str, PorcusStatus> {
+	if s.is_null() {
+		return Err(PorcusStatus::NullPointer);
+	}
+	str::from_utf8(slice::from_raw_parts(s.cast::<u8>(), length))
+		.map_err(|_| PorcusStatus::InvalidUtf8)
+}
+
+/// Replaces a suffix of a transformer.
+unsafe fn set_suffix<F>(
+	transformer: *mut PorcusTransformer,
+	suffix: *const c_char,
+	length: usize,
+	set: F,
+) -> PorcusStatus
+where
+	F: FnOnce(PigLatinTransformer, &str) -> PigLatinTransformer,
+{
+	let Some(transformer) = transformer.as_mut() else {
+		return PorcusStatus::NullPointer;
+	};
+	match borrow_str(suffix, length) {
+		Ok(suffix) => {
+			let previous = mem::take(&mut transformer.transformer);
+			transformer.transformer = set(previous, suffix);
+			PorcusStatus::Ok
+		}
+		Err(status) => status,
+	}
+}
+
+#[cfg(test)]
+mod test_ffi {
+	use super::*;
+
+	/// Translates a string, returning the status and the translation.
+	fn transform(transformer: *const PorcusTransformer, text: &[u8]) -> (PorcusStatus, String) {
+		let mut output = PorcusString::null();
+		unsafe {
+			let status =
+				porcus_transform(transformer, text.as_ptr().cast(), text.len(), &mut output);
+			let pig = if output.data.is_null() {
+				String::new()
+			} else {
+				let bytes = slice::from_raw_parts(output.data.cast::<u8>(), output.length + 1);
+				assert_eq!(bytes.last(), Some(&0));
+				String::from_utf8(bytes[..output.length].to_vec()).unwrap()
+			};
+			porcus_string_free(output);
+			(status, pig)
+		}
+	}
+
+	#[test]
+	fn round_trip() {
+		let transformer = porcus_transformer_new();
+		assert_eq!(
+			transform(transformer, "Pig latin".as_bytes()),
+			(PorcusStatus::Ok, String::from("Igpay atinlay"))
+		);
+		assert_eq!(
+			transform(transformer, b""),
+			(PorcusStatus::Ok, String::new())
+		);
+		assert_eq!(
+			transform(transformer, "nul\0byte".as_bytes()),
+			(PorcusStatus::Ok, String::from("ulnay\0ytebay"))
+		);
+		unsafe { porcus_transformer_free(transformer) };
+	}
+
+	#[test]
+	fn suffixes() {
+		let transformer = porcus_transformer_new();
+		unsafe {
+			assert_eq!(
+				porcus_transformer_set_consonant_suffix(transformer, c"ey".as_ptr(), 2),
+				PorcusStatus::Ok
+			);
+			assert_eq!(
+				porcus_transformer_set_vowel_suffix(transformer, c"yey".as_ptr(), 3),
+				PorcusStatus::Ok
+			);
+			assert_eq!(
+				porcus_transformer_set_vowel_suffix(transformer, b"\xff".as_ptr().cast(), 1),
+				PorcusStatus::InvalidUtf8
+			);
+		}
+		assert_eq!(
+			transform(transformer, "egg nix".as_bytes()),
+			(PorcusStatus::Ok, String::from("eggyey ixney"))
+		);
+		unsafe { porcus_transformer_free(transformer) };
+	}
+
+	#[test]
+	fn errors() {
+		let transformer = porcus_transformer_new();
+		assert_eq!(
+			transform(transformer, b"caf\xe9"),
+			(PorcusStatus::InvalidUtf8, String::new())
+		);
+		assert_eq!(
+			transform(ptr::null(), b"pig"),
+			(PorcusStatus::NullPointer, String::new())
+		);
+		unsafe {
+			let mut output = PorcusString::null();
+			assert_eq!(
+				porcus_transform(transformer, ptr::null(), 3, &mut output),
+				PorcusStatus::NullPointer
+			);
+			assert!(output.data.is_null());
+			assert_eq!(
+				porcus_transform(transformer, c"pig".as_ptr(), 3, ptr::null_mut()),
+				PorcusStatus::NullPointer
+			);
+			assert_eq!(
+				porcus_transformer_set_consonant_suffix(ptr::null_mut(), c"ay".as_ptr(), 2),
+				PorcusStatus::NullPointer
+			);
+			porcus_transformer_free(ptr::null_mut());
+			porcus_string_free(PorcusString::null());
+			porcus_transformer_free(transformer);
+		}
+	}
+
+	#[test]
+	fn status_messages() {
+		let message = |status| unsafe { CStr::from_ptr(porcus_status_message(status)) };
+		assert_eq!(message(PorcusStatus::Ok as c_int), c"success");
+		assert_eq!(
+			message(PorcusStatus::InvalidUtf8 as c_int),
+			c"invalid UTF-8"
+		);
+		assert_eq!(message(4), c"unknown status");
+		assert_eq!(message(-1), c"unknown status");
+	}
+}
@@ -0,0 +1,66 @@
+//! Checks the C header, and calls the library from a C program.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Path of the committed header, relative to the crate.
+const HEADER: &str = "include/porcus.h";
+
+fn crate_dir() -> &'static Path {
+	Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Gets the directory of the libraries built for tests, `target/<profile>/deps`, next to this
+/// test.
+fn library_dir() -> PathBuf {
+	let test = env::current_exe().unwrap();
+	test.parent().unwrap().to_path_buf()
+}
+
+/// Checks that the header matches the bindings. Set `PORCUS_UPDATE_HEADER` to regenerate it.
+#[test]
+fn header_is_up_to_date() {
+	let bindings = cbindgen::generate(crate_dir()).unwrap();
+	let path = crate_dir().join(HEADER);
+	if env::var_os("PORCUS_UPDATE_HEADER").is_some() {
+		bindings.write_to_file(&path);
+	}
+	let mut generated = Vec::new();
+	bindings.write(&mut generated);
+	assert!(
+		fs::read(&path).unwrap() == generated,
+		"{} is out of date, rerun this test with PORCUS_UPDATE_HEADER=1",
+		HEADER
+	);
+}
+
+#[test]
+#[cfg(unix)]
+fn c_program() {
+	let out_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+	let program = out_dir.join("porcus_test");
+	let compiled = Command::new(env::var_os("CC").unwrap_or_else(|| "cc".into()))
+		.arg("-std=c99")
+		.arg("-Wall")
+		.arg("-Werror")
+		.arg("-I")
+		.arg(crate_dir().join("include"))
+		.arg(crate_dir().join("tests/porcus_test.c"))
+		.arg(library_dir().join("libporcus_ffi.a"))
+		.args(["-lpthread", "-ldl", "-lm"])
+		.arg("-o")
+		.arg(&program)
+		.status()
+		.unwrap();
+	assert!(compiled.success());
+
+	let output = Command::new(&program).output().unwrap();
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	assert_eq!(output.stdout, b"ok\n");
+}
@@ -0,0 +1,48 @@
+/* Calls porcus from C, linked with the static library. */
+
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "porcus.h"
+
+static void assert_transform(const PorcusTransformer *transformer, const char *text,
+		const char *expected) {
+	PorcusString pig;
+	PorcusStatus status = porcus_transform(transformer, text, strlen(text), &pig);
+	if (status != PORCUS_STATUS_OK) {
+		fprintf(stderr, "%s: %s\n", text, porcus_status_message(status));
+	}
+	assert(status == PORCUS_STATUS_OK);
+	assert(pig.length == strlen(expected));
+	assert(strcmp(pig.data, expected) == 0);
+	porcus_string_free(pig);
+}
+
+int main(void) {
+	PorcusTransformer *transformer = porcus_transformer_new();
+	assert(transformer != NULL);
+
+	assert_transform(transformer, "Pig latin", "Igpay atinlay");
+	assert_transform(transformer, "à l’œuf", "àway œufl’ay");
+	assert_transform(transformer, "", "");
+
+	assert(porcus_transformer_set_consonant_suffix(transformer, "ey", 2) == PORCUS_STATUS_OK);
+	assert(porcus_transformer_set_vowel_suffix(transformer, "yey", 3) == PORCUS_STATUS_OK);
+	assert_transform(transformer, "egg nix", "eggyey ixney");
+
+	PorcusString pig;
+	assert(porcus_transform(transformer, "caf\xe9", 4, &pig) == PORCUS_STATUS_INVALID_UTF8);
+	assert(pig.data == NULL);
+	porcus_string_free(pig);
+	assert(porcus_transform(NULL, "pig", 3, &pig) == PORCUS_STATUS_NULL_POINTER);
+	assert(porcus_transformer_set_vowel_suffix(transformer, "\xff", 1)
+			== PORCUS_STATUS_INVALID_UTF8);
+	assert(strcmp(porcus_status_message(PORCUS_STATUS_INVALID_UTF8), "invalid UTF-8") == 0);
+	assert(strcmp(porcus_status_message(42), "unknown status") == 0);
+
+	porcus_transformer_free(transformer);
+	porcus_transformer_free(NULL);
+	puts("ok");
+	return 0;
+}
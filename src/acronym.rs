@@ -0,0 +1,220 @@
+//! Detection and handling of acronyms and initialisms.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::acronym::AcronymPolicy;
+//! use porcus::PigLatinTransformer;
+//!
+//! let transformer = PigLatinTransformer::default().with_acronym_policy(AcronymPolicy::Spell);
+//! assert_eq!(transformer.to_pig_latin("My TV"), "Ymay eetay-eevay");
+//! ```
+
+use crate::case::{self, Case};
+use crate::orthography::Orthography;
+use crate::syllable;
+use alloc::vec::Vec;
+use core::fmt;
+use unicode_script::{Script, UnicodeScript};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Longest uppercase word always considered an initialism, e.g. `USA`.
+const MAX_INITIALISM_LENGTH: usize = 3;
+
+/// How acronyms and initialisms are translated.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum AcronymPolicy {
+	/// Acronyms are translated like other words, e.g. `TV` becomes `VTAY`.
+	#[default]
+	Transform,
+	/// Uppercase words of at least two letters are left unchanged, as well as dotted initialisms
+	/// and uppercase Latin prefixes before digits or another script, e.g. `TV` in `TV9मराठी`.
+	Keep,
+	/// Each letter is translated as it is spoken, e.g. `TV` becomes `eetay-eevay`.
+	Spell,
+}
+
+impl fmt::Display for AcronymPolicy {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Transform => "transform",
+			Self::Keep => "keep",
+			Self::Spell => "spell",
+		})
+	}
+}
+
+impl AcronymPolicy {
+	/// Names of all policies, as accepted by [`AcronymPolicy::from_name`].
+	pub const NAMES: &'static [&'static str] = &["transform", "keep", "spell"];
+
+	/// Gets a policy by name.
+	///
+	/// Returns `None` for unknown names.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"transform" => Some(Self::Transform),
+			"keep" => Some(Self::Keep),
+			"spell" => Some(Self::Spell),
+			_ => None,
+		}
+	}
+}
+
+/// Checks whether a word is an acronym or initialism read letter by letter, as spelled by
+/// [`AcronymPolicy::Spell`].
+///
+/// Acronyms are uppercase words of at least two letters which are either short, unpronounceable
+/// in the orthography, or written with dots.
+///
+/// ```
+/// # use porcus::acronym::is_acronym;
+/// # use porcus::orthography::Orthography;
+/// assert!(is_acronym("TV", Orthography::English));
+/// assert!(is_acronym("HTML", Orthography::English));
+/// assert!(is_acronym("U.S.A.", Orthography::English));
+/// ```
+///
+/// Longer uppercase words which can be read aloud are not detected, as they cannot be told
+/// apart from shouting. Use [`Exceptions`](crate::exceptions::Exceptions) to list them.
+///
+/// ```
+/// # use porcus::acronym::is_acronym;
+/// # use porcus::orthography::Orthography;
+/// assert!(!is_acronym("NASA", Orthography::English));
+/// assert!(!is_acronym("HELLO", Orthography::English));
+/// assert!(!is_acronym("Tv", Orthography::English));
+/// assert!(!is_acronym("I", Orthography::English));
+/// ```
+#[must_use]
+pub fn is_acronym(word: &str, orthography: Orthography) -> bool {
+	if case::detect_case(word) != Case::Upper {
+		return false;
+	}
+	if is_dotted(word) {
+		return true;
+	}
+	let graphemes = word.graphemes(true).collect::<Vec<&str>>();
+	graphemes.len() >= 2
+		&& graphemes.iter().all(|grapheme| is_letter(grapheme))
+		&& (graphemes.len() <= MAX_INITIALISM_LENGTH
+			|| !syllable::is_pronounceable(word, orthography))
+}
+
+/// Finds the length in bytes of the acronym a word starts with, as kept by
+/// [`AcronymPolicy::Keep`].
+///
+/// Uppercase words of at least two letters are kept whole, whether they can be read aloud or not,
+/// and so are dotted initialisms.
+///
+/// ```
+/// # use porcus::acronym::kept_acronym_length;
+/// assert_eq!(kept_acronym_length("NASA"), Some(4));
+/// assert_eq!(kept_acronym_length("U.S.A."), Some(6));
+/// assert_eq!(kept_acronym_length("Nasa"), None);
+/// assert_eq!(kept_acronym_length("I"), None);
+/// ```
+///
+/// A word may also start with an uppercase Latin acronym followed by digits or another script.
+///
+/// ```
+/// # use porcus::acronym::kept_acronym_length;
+/// assert_eq!(kept_acronym_length("TV9मराठी"), Some(2));
+/// assert_eq!(kept_acronym_length("HTML5"), Some(4));
+/// assert_eq!(kept_acronym_length("TVs"), None);
+/// ```
+#[must_use]
+pub fn kept_acronym_length(word: &str) -> Option<usize> {
+	if case::detect_case(word) == Case::Upper {
+		let graphemes = word.graphemes(true).collect::<Vec<&str>>();
+		if is_dotted(word)
+			|| (graphemes.len() >= 2 && graphemes.iter().all(|grapheme| is_letter(grapheme)))
+		{
+			return Some(word.len());
+		}
+	}
+	let mut letter_count = 0;
+	let mut length = 0;
+	for grapheme in word.graphemes(true) {
+		match grapheme.chars().next() {
+			Some(c) if c.is_uppercase() && c.script() == Script::Latin => {
+				letter_count += 1;
+				length += grapheme.len();
+			}
+			Some(c) if c.is_numeric() || (c.is_alphabetic() && c.script() != Script::Latin) => {
+				return (letter_count >= 2).then_some(length);
+			}
+			_ => return None,
+		}
+	}
+	None
+}
+
+/// Checks whether a word is single letters separated by dots, e.g. `U.S.A.`.
+fn is_dotted(word: &str) -> bool {
+	let word = word.strip_suffix('.').unwrap_or(word);
+	let mut letter_count = 0;
+	let all_letters = word.split('.').all(|part| {
+		letter_count += 1;
+		let mut graphemes = part.graphemes(true);
+		graphemes.next().is_some_and(is_letter) && graphemes.next().is_none()
+	});
+	all_letters && letter_count >= 2
+}
+
+fn is_letter(grapheme: &str) -> bool {
+	grapheme.chars().next().is_some_and(char::is_alphabetic)
+}
+
+#[cfg(test)]
+mod test_is_acronym {
+	use super::*;
+
+	#[test]
+	fn initialisms() {
+		for word in ["TV", "FBI", "USA", "ÉU", "BBC"] {
+			assert!(is_acronym(word, Orthography::English), "{}", word);
+		}
+	}
+
+	#[test]
+	fn unpronounceable() {
+		for word in ["HTML", "HTTPS", "PDFS", "NDAS"] {
+			assert!(is_acronym(word, Orthography::English), "{}", word);
+		}
+		assert!(is_acronym("SPQR", Orthography::German));
+		assert!(!is_acronym("SCHWA", Orthography::German));
+	}
+
+	#[test]
+	fn dotted() {
+		for word in ["U.S.A", "U.S.A.", "É.U."] {
+			assert!(is_acronym(word, Orthography::English), "{}", word);
+		}
+		for word in ["U.", "U..S", "US.A", "u.s.a", ".U.S"] {
+			assert!(!is_acronym(word, Orthography::English), "{}", word);
+		}
+	}
+
+	#[test]
+	fn kept() {
+		for word in ["TV", "NASA", "HELLO", "ÉTÉ", "É.U.", "ΝΑΤΟ", "BBCहिंदी"] {
+			assert_eq!(kept_acronym_length(word), Some(word.len()), "{}", word);
+		}
+		assert_eq!(kept_acronym_length("TV9"), Some(2));
+		assert_eq!(kept_acronym_length("BBC2हिंदी"), Some(3));
+		for word in ["", "A", "Nasa", "iOS", "T9", "TVs", "9TV", "A-B"] {
+			assert_eq!(kept_acronym_length(word), None, "{}", word);
+		}
+	}
+
+	#[test]
+	fn not_acronyms() {
+		for word in ["", "A", "NATO", "SCUBA", "TV9", "A-B", "Usa", "iOS"] {
+			assert!(!is_acronym(word, Orthography::English), "{}", word);
+		}
+	}
+}
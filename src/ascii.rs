@@ -0,0 +1,178 @@
+//! Fast paths for ASCII text.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::ascii;
+//!
+//! assert_eq!(
+//!     ascii::split_word_bounds("Don't stop, 3.14!"),
+//!     Some(vec!["Don't", " ", "stop", ",", " ", "3.14", "!"])
+//! );
+//! assert_eq!(ascii::split_word_bounds("naïve"), None);
+//! ```
+
+use alloc::vec::Vec;
+
+/// Word break property of an ASCII character, as defined by
+/// [UAX #29](https://www.unicode.org/reports/tr29/#Word_Boundaries).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum WordBreak {
+	CarriageReturn,
+	LineFeed,
+	Newline,
+	Space,
+	Letter,
+	Numeric,
+	Underscore,
+	MidLetter,
+	MidNum,
+	MidNumLet,
+	SingleQuote,
+	Other,
+}
+
+impl WordBreak {
+	const fn of(byte: u8) -> Self {
+		match byte {
+			b'\r' => Self::CarriageReturn,
+			b'\n' => Self::LineFeed,
+			b'\x0b' | b'\x0c' => Self::Newline,
+			b' ' => Self::Space,
+			b'A'..=b'Z' | b'a'..=b'z' => Self::Letter,
+			b'0'..=b'9' => Self::Numeric,
+			b'_' => Self::Underscore,
+			b':' => Self::MidLetter,
+			b',' | b';' => Self::MidNum,
+			b'.' => Self::MidNumLet,
+			b'\'' => Self::SingleQuote,
+			_ => Self::Other,
+		}
+	}
+
+	const fn is_newline(self) -> bool {
+		matches!(self, Self::CarriageReturn | Self::LineFeed | Self::Newline)
+	}
+
+	/// Checks whether the character may join letters, e.g. `'` in `don't`.
+	const fn is_mid_letter(self) -> bool {
+		matches!(self, Self::MidLetter | Self::MidNumLet | Self::SingleQuote)
+	}
+
+	/// Checks whether the character may join digits, e.g. `,` in `1,000`.
+	const fn is_mid_num(self) -> bool {
+		matches!(self, Self::MidNum | Self::MidNumLet | Self::SingleQuote)
+	}
+}
+
+/// Splits ASCII text at word boundaries, like
+/// [`split_word_bounds`](https://unicode-rs.github.io/unicode-segmentation/unicode_segmentation/trait.UnicodeSegmentation.html#tymethod.split_word_bounds)
+/// but without looking up Unicode properties.
+///
+/// Returns `None` if the text is not ASCII.
+#[must_use]
+pub fn split_word_bounds(s: &str) -> Option<Vec<&str>> {
+	if !s.is_ascii() {
+		return None;
+	}
+	let classes = s.bytes().map(WordBreak::of).collect::<Vec<WordBreak>>();
+	let mut words = Vec::new();
+	let mut start = 0;
+	for index in 1..classes.len() {
+		if is_boundary(&classes, index) {
+			words.push(&s[start..index]);
+			start = index;
+		}
+	}
+	if start < s.len() {
+		words.push(&s[start..]);
+	}
+	Some(words)
+}
+
+/// Checks for a word boundary before the character at an index, following the rules of UAX #29
+/// which apply to ASCII.
+fn is_boundary(classes: &[WordBreak], index: usize) -> bool {
+	use WordBreak::{CarriageReturn, Letter, LineFeed, Numeric, Space, Underscore};
+
+	let before = classes[index - 1];
+	let after = classes[index];
+	let before_before = index.checked_sub(2).map(|index| classes[index]);
+	let after_after = classes.get(index + 1).copied();
+	match (before, after) {
+		// WB3
+		(CarriageReturn, LineFeed) => false,
+		// WB3a, WB3b
+		_ if before.is_newline() || after.is_newline() => true,
+		// WB3d
+		(Space, Space) => false,
+		// WB5, WB8, WB9, WB10
+		(Letter | Numeric, Letter | Numeric) => false,
+		// WB6
+		(Letter, mid) if mid.is_mid_letter() && after_after == Some(Letter) => false,
+		// WB7
+		(mid, Letter) if mid.is_mid_letter() && before_before == Some(Letter) => false,
+		// WB12
+		(Numeric, mid) if mid.is_mid_num() && after_after == Some(Numeric) => false,
+		// WB11
+		(mid, Numeric) if mid.is_mid_num() && before_before == Some(Numeric) => false,
+		// WB13a, WB13b
+		(Letter | Numeric | Underscore, Underscore) | (Underscore, Letter | Numeric) => false,
+		// WB999
+		_ => true,
+	}
+}
+
+#[cfg(test)]
+mod test_split_word_bounds {
+	use super::*;
+	use unicode_segmentation::UnicodeSegmentation;
+
+	/// Characters of every word break class, and some of each class twice.
+	const ALPHABET: &[u8] = b"aZ09_:.,;'\"\t \r\n\x0b\x0c-!";
+
+	fn assert_same_split(s: &str) {
+		assert_eq!(
+			split_word_bounds(s),
+			Some(s.split_word_bounds().collect::<Vec<&str>>()),
+			"{:?}",
+			s
+		);
+	}
+
+	#[test]
+	fn all_pairs() {
+		for first in 0..=0x7f_u8 {
+			for second in 0..=0x7f_u8 {
+				assert_same_split(std::str::from_utf8(&[first, second]).unwrap());
+			}
+		}
+	}
+
+	#[test]
+	fn all_short_strings() {
+		let mut s = Vec::new();
+		for length in 3..=4_u32 {
+			for mut n in 0..ALPHABET.len().pow(length) {
+				s.clear();
+				for _ in 0..length {
+					s.push(ALPHABET[n % ALPHABET.len()]);
+					n /= ALPHABET.len();
+				}
+				assert_same_split(std::str::from_utf8(&s).unwrap());
+			}
+		}
+	}
+
+	#[test]
+	fn sentences() {
+		assert_same_split("");
+		assert_same_split("Hello, world! It's 10:30 on 2024-01-01.\r\n");
+		assert_same_split("e.g. a_b 1,000.5 'quoted' \"double\"  tabs\t\tand...dots");
+	}
+
+	#[test]
+	fn not_ascii() {
+		assert_eq!(split_word_bounds("café"), None);
+	}
+}
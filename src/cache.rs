@@ -0,0 +1,325 @@
+//! Memoization of translated words.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::cache::CachedTransformer;
+//! use porcus::PigLatinTransformer;
+//!
+//! let transformer = CachedTransformer::new(PigLatinTransformer::default(), 10_000);
+//! assert_eq!(transformer.to_pig_latin("the pig and the dog"), "ethay igpay andway ethay ogday");
+//!
+//! let stats = transformer.stats();
+//! assert_eq!((stats.hits, stats.misses), (1, 4));
+//! ```
+
+use crate::encoding;
+use crate::PigLatinTransformer;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::num::NonZeroUsize;
+use lru::LruCache;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// Transformer remembering the translations of the most recently seen words.
+///
+/// Words are cached in lowercase, so that `the`, `The` and `THE` share one entry, and their
+/// translation is recased. Words with mixed case such as `McDonald`, whose translation depends on
+/// where their capitals are, are cached as written, like uppercase words when acronyms are kept or
+/// spelled out and words such as `ẞ` which cannot be recased from lowercase. Spaces and
+/// punctuation are not cached. The transformer cannot be
+/// changed once wrapped, so cached translations always match its settings. Clones start with an
+/// empty cache.
+///
+/// The cache is shared between threads, e.g. with
+/// [`transform_many`](CachedTransformer::transform_many), behind a lock.
+pub struct CachedTransformer {
+	transformer: PigLatinTransformer,
+	cache: WordCache,
+}
+
+impl fmt::Debug for CachedTransformer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CachedTransformer")
+			.field("transformer", &self.transformer)
+			.field("stats", &self.stats())
+			.finish()
+	}
+}
+
+impl fmt::Display for CachedTransformer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} cached", self.transformer)
+	}
+}
+
+impl Clone for CachedTransformer {
+	fn clone(&self) -> Self {
+		Self::new(self.transformer.clone(), self.cache.capacity().get())
+	}
+}
+
+impl CachedTransformer {
+	/// Wraps a transformer with a cache of up to `capacity` words, at least one.
+	#[must_use]
+	pub fn new(transformer: PigLatinTransformer, capacity: usize) -> Self {
+		Self {
+			transformer,
+			cache: WordCache::new(NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN)),
+		}
+	}
+
+	/// Gets the wrapped transformer.
+	#[must_use]
+	pub const fn transformer(&self) -> &PigLatinTransformer {
+		&self.transformer
+	}
+
+	/// Unwraps the transformer, dropping the cache.
+	#[must_use]
+	pub fn into_inner(self) -> PigLatinTransformer {
+		self.transformer
+	}
+
+	/// Gets the number of cache hits and misses so far, and the cache size.
+	#[must_use]
+	pub fn stats(&self) -> CacheStats {
+		self.cache.stats()
+	}
+
+	/// Empties the cache and resets its statistics.
+	pub fn clear(&self) {
+		self.cache.clear();
+	}
+
+	/// Returns the pig latin translation of a string.
+	pub fn to_pig_latin<S: Into<String>>(&self, s: S) -> String {
+		self.transformer
+			.to_cached_pig_latin(&s.into(), Some(&self.cache))
+	}
+
+	/// Returns the pig latin translation of bytes in UTF-8.
+	///
+	/// Invalid UTF-8 sequences are passed through unchanged, and split words around them.
+	#[must_use]
+	pub fn to_pig_latin_bytes(&self, bytes: &[u8]) -> Vec<u8> {
+		encoding::map_utf8_runs(bytes, |text| self.to_pig_latin(text))
+	}
+
+	/// Returns the pig latin translations of many strings, in parallel and in input order.
+	#[cfg(feature = "rayon")]
+	pub fn transform_many<I>(&self, inputs: I) -> Vec<String>
+	where
+		I: IntoParallelIterator,
+		I::Item: Into<String>,
+	{
+		inputs
+			.into_par_iter()
+			.map(|input| self.to_pig_latin(input))
+			.collect()
+	}
+}
+
+/// Statistics of a word cache.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct CacheStats {
+	/// Number of words found in the cache.
+	pub hits: u64,
+	/// Number of words translated and added to the cache.
+	pub misses: u64,
+	/// Number of words in the cache.
+	pub len: usize,
+	/// Maximum number of words in the cache.
+	pub capacity: usize,
+}
+
+impl CacheStats {
+	/// Gets the proportion of words found in the cache, or 0 if no words were looked up.
+	///
+	/// ```
+	/// # use porcus::cache::CacheStats;
+	/// let stats = CacheStats { hits: 3, misses: 1, len: 1, capacity: 10 };
+	/// assert_eq!(stats.hit_rate(), 0.75);
+	/// ```
+	#[must_use]
+	#[allow(clippy::cast_precision_loss)]
+	pub fn hit_rate(&self) -> f64 {
+		let lookups = self.hits + self.misses;
+		if lookups == 0 {
+			0.0
+		} else {
+			self.hits as f64 / lookups as f64
+		}
+	}
+}
+
+impl fmt::Display for CacheStats {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{} hits, {} misses ({:.1}% hits), {}/{} words cached",
+			self.hits,
+			self.misses,
+			self.hit_rate() * 100.0,
+			self.len,
+			self.capacity
+		)
+	}
+}
+
+/// Least recently used words and their translations.
+pub(crate) struct WordCache {
+	state: Mutex<CacheState>,
+}
+
+struct CacheState {
+	words: LruCache<String, String>,
+	hits: u64,
+	misses: u64,
+}
+
+impl WordCache {
+	fn new(capacity: NonZeroUsize) -> Self {
+		Self {
+			state: Mutex::new(CacheState {
+				words: LruCache::new(capacity),
+				hits: 0,
+				misses: 0,
+			}),
+		}
+	}
+
+	/// Gets the cached translation of a word, or translates and caches it.
+	///
+	/// The lock is released while translating, so that threads translating different words do
+	/// not wait for each other.
+	pub(crate) fn get_or_insert_with<F: FnOnce() -> String>(&self, word: &str, f: F) -> String {
+		{
+			let mut state = self.lock();
+			if let Some(pig) = state.words.get(word).cloned() {
+				state.hits += 1;
+				return pig;
+			}
+			state.misses += 1;
+		}
+		let pig = f();
+		self.lock().words.put(word.to_string(), pig.clone());
+		pig
+	}
+
+	fn capacity(&self) -> NonZeroUsize {
+		self.lock().words.cap()
+	}
+
+	fn stats(&self) -> CacheStats {
+		let state = self.lock();
+		CacheStats {
+			hits: state.hits,
+			misses: state.misses,
+			len: state.words.len(),
+			capacity: state.words.cap().get(),
+		}
+	}
+
+	fn clear(&self) {
+		let mut state = self.lock();
+		state.words.clear();
+		state.hits = 0;
+		state.misses = 0;
+	}
+
+	/// Locks the cache. A panic while translating never leaves it inconsistent, so poisoning is
+	/// ignored.
+	fn lock(&self) -> MutexGuard<'_, CacheState> {
+		self.state.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
+#[cfg(test)]
+mod test_cached_transformer {
+	use super::*;
+	use crate::acronym::AcronymPolicy;
+	use crate::dialect::Dialect;
+	use crate::exceptions::Exceptions;
+
+	const TEXT: &str = "The McDonald farm had a pig. THE PIG, the pig and the farm!";
+
+	#[test]
+	fn same_output() {
+		for transformer in [
+			PigLatinTransformer::default(),
+			PigLatinTransformer::default().with_sentence_capitalization(true),
+			PigLatinTransformer::new("ey", "yey")
+				.with_dialect(Dialect::from_preset("playground").unwrap()),
+		] {
+			let cached = CachedTransformer::new(transformer.clone(), 4);
+			assert_eq!(cached.to_pig_latin(TEXT), transformer.to_pig_latin(TEXT));
+			assert_eq!(cached.to_pig_latin(TEXT), transformer.to_pig_latin(TEXT));
+		}
+	}
+
+	#[test]
+	fn stats() {
+		let cached = CachedTransformer::new(PigLatinTransformer::default(), 100);
+		assert_eq!(cached.to_pig_latin("pig Pig PIG"), "igpay Igpay IGPAY");
+		assert_eq!(
+			cached.stats(),
+			CacheStats {
+				hits: 2,
+				misses: 1,
+				len: 1,
+				capacity: 100
+			}
+		);
+
+		cached.clear();
+		assert_eq!(
+			cached.stats(),
+			CacheStats {
+				capacity: 100,
+				..CacheStats::default()
+			}
+		);
+	}
+
+	#[test]
+	fn case_sensitive_words() {
+		let transformer = PigLatinTransformer::default()
+			.with_acronym_policy(AcronymPolicy::Keep)
+			.with_exceptions(Exceptions::new().with_replacement("github", "ItHubgay"));
+		let cached = CachedTransformer::new(transformer.clone(), 100);
+		let text = "McDonald mcdonald NASA nasa Nasa GitHub GITHUB github ẞ İZMİR";
+		assert_eq!(cached.to_pig_latin(text), transformer.to_pig_latin(text));
+		let stats = cached.stats();
+		assert_eq!((stats.hits, stats.misses), (1, 9));
+	}
+
+	#[test]
+	fn least_recently_used_words_are_evicted() {
+		let cached = CachedTransformer::new(PigLatinTransformer::default(), 2);
+		cached.to_pig_latin("a b a c a");
+		let stats = cached.stats();
+		assert_eq!((stats.hits, stats.misses, stats.len), (2, 3, 2));
+		cached.to_pig_latin("b");
+		assert_eq!(cached.stats().misses, 4);
+	}
+
+	#[test]
+	fn clones_are_independent() {
+		let cached = CachedTransformer::new(PigLatinTransformer::default(), 10);
+		cached.to_pig_latin("pig");
+		let clone = cached.clone();
+		assert_eq!(
+			clone.stats(),
+			CacheStats {
+				capacity: 10,
+				..CacheStats::default()
+			}
+		);
+		assert_eq!(clone.into_inner(), PigLatinTransformer::default());
+	}
+}
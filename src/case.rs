@@ -3,18 +3,49 @@
 //! # Usage
 //!
 //! ```
-//! use porcus::case::{self, Case};
+//! use porcus::case::{self, Case, Locale};
 //!
 //! let s = "HELLO";
 //! assert_eq!(case::detect_case(&s), Case::Upper);
 //! assert_eq!(case::to_case(s, Case::Sentence), "Hello");
+//! assert_eq!(Locale::Turkish.to_case("istanbul", Case::Sentence), "İstanbul");
 //! ```
 
-use std::fmt;
+use crate::style;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+use core::ops::Range;
+use unicode_normalization::char::{canonical_combining_class, is_combining_mark};
 use unicode_segmentation::UnicodeSegmentation;
 
+/// Combining class of marks placed above the letter, e.g. `◌́`.
+const ABOVE: u8 = 230;
+
+/// Combining dot above, kept in Lithuanian on lowercase `i` and `j` with an accent.
+const DOT_ABOVE: char = '\u{307}';
+
+/// Letters losing their dot under an accent, as listed by Unicode.
+const SOFT_DOTTED: &[char] = &['i', 'j', 'į', 'ɨ', 'ɉ', 'ʝ'];
+
+/// Characters ignored when looking for the letters around a sigma, besides combining marks.
+const CASE_IGNORABLE: &[char] = &['\'', '’', '.', ':', '·', '\u{ad}'];
+
 /// Case of a word.
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+	feature = "python",
+	pyo3::pyclass(
+		eq,
+		eq_int,
+		from_py_object,
+		frozen,
+		hash,
+		str,
+		module = "porcus",
+		rename_all = "SCREAMING_SNAKE_CASE"
+	)
+)]
 pub enum Case {
 	/// All characters are lowercase or uncased.
 	Lower,
@@ -23,15 +54,10 @@ pub enum Case {
 	/// The first character is uppercase. All others are lowercase or uncased.
 	Sentence,
 	/// No consistent case pattern.
+	#[default]
 	Mixed,
 }
 
-impl Default for Case {
-	fn default() -> Self {
-		Self::Mixed
-	}
-}
-
 impl fmt::Display for Case {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		f.write_str(match self {
@@ -43,6 +69,226 @@ impl fmt::Display for Case {
 	}
 }
 
+/// Language-specific case mapping rules.
+///
+/// Greek final sigma, titlecase digraphs such as `ǅ` and [styled letters](crate::style) are
+/// handled in all locales.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Locale {
+	/// Default Unicode mappings.
+	#[default]
+	Root,
+	/// `ij` is capitalized as a single letter, e.g. `IJsland`.
+	Dutch,
+	/// `ß` is uppercased as `ẞ` rather than `SS`.
+	German,
+	/// `i` and `j` keep their dot under an accent, e.g. `Ì` becomes `i̇̀`.
+	Lithuanian,
+	/// Dotted and dotless `i` are separate letters, e.g. `i` becomes `İ`. Also used for
+	/// Azerbaijani.
+	Turkish,
+}
+
+impl fmt::Display for Locale {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Root => "root",
+			Self::Dutch => "dutch",
+			Self::German => "german",
+			Self::Lithuanian => "lithuanian",
+			Self::Turkish => "turkish",
+		})
+	}
+}
+
+impl Locale {
+	/// Names of all locales, as accepted by [`Locale::from_name`].
+	pub const NAMES: &'static [&'static str] =
+		&["root", "dutch", "german", "lithuanian", "turkish"];
+
+	/// Gets a locale by name.
+	///
+	/// Returns `None` for unknown names.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"root" => Some(Self::Root),
+			"dutch" => Some(Self::Dutch),
+			"german" => Some(Self::German),
+			"lithuanian" => Some(Self::Lithuanian),
+			"turkish" => Some(Self::Turkish),
+			_ => None,
+		}
+	}
+
+	/// Detects the case of a word, see [`detect_case`].
+	///
+	/// ```
+	/// # use porcus::case::{Case, Locale};
+	/// assert_eq!(Locale::Root.detect_case("IJsland"), Case::Mixed);
+	/// assert_eq!(Locale::Dutch.detect_case("IJsland"), Case::Sentence);
+	/// ```
+	#[must_use]
+	pub fn detect_case(self, s: &str) -> Case {
+		let initial_length = self.initial_length(s);
+		if initial_length == 0 {
+			return Case::Lower;
+		}
+		let (initial, rest) = s.split_at(initial_length);
+		let first_is_lower = !initial.chars().any(|c| c.is_uppercase() || is_titlecase(c));
+		let first_is_upper = !initial.chars().any(char::is_lowercase);
+		let rest_is_upper = rest.chars().all(|c| !c.is_lowercase());
+		let rest_is_lower = rest.chars().all(|c| !c.is_uppercase());
+
+		match (first_is_lower, first_is_upper, rest_is_lower, rest_is_upper) {
+			(true, _, true, _) => Case::Lower,
+			(_, true, true, _) => Case::Sentence,
+			(_, true, _, true) => Case::Upper,
+			_ => Case::Mixed,
+		}
+	}
+
+	/// Returns the equivalent of a string as the specified case, see [`to_case`].
+	///
+	/// ```
+	/// # use porcus::case::{Case, Locale};
+	/// assert_eq!(Locale::Turkish.to_case("DİYARBAKIR", Case::Lower), "diyarbakır");
+	/// assert_eq!(Locale::Dutch.to_case("ijsland", Case::Sentence), "IJsland");
+	/// assert_eq!(Locale::German.to_case("straße", Case::Upper), "STRAẞE");
+	/// ```
+	pub fn to_case<S: Into<String>>(self, s: S, case: Case) -> String {
+		let s = s.into();
+		match case {
+			Case::Lower => self.to_lowercase(&s),
+			Case::Upper => self.to_uppercase(&s),
+			Case::Sentence => self.to_sentence_case(&s),
+			Case::Mixed => s,
+		}
+	}
+
+	/// Converts a string to lowercase.
+	///
+	/// ```
+	/// # use porcus::case::Locale;
+	/// assert_eq!(Locale::Root.to_lowercase("ΟΔΟΣ 𝐇𝐄𝐘"), "οδος 𝐡𝐞𝐲");
+	/// assert_eq!(Locale::Lithuanian.to_lowercase("ĨS"), "i\u{307}\u{303}s");
+	/// ```
+	#[must_use]
+	pub fn to_lowercase(self, s: &str) -> String {
+		self.lowercase_range(s, 0..s.len())
+	}
+
+	/// Converts a string to uppercase.
+	///
+	/// ```
+	/// # use porcus::case::Locale;
+	/// assert_eq!(Locale::Root.to_uppercase("𝐡𝐞𝐲 ⓨⓞⓤ"), "𝐇𝐄𝐘 ⓎⓄⓊ");
+	/// assert_eq!(Locale::Turkish.to_uppercase("iğne"), "İĞNE");
+	/// ```
+	#[must_use]
+	pub fn to_uppercase(self, s: &str) -> String {
+		self.uppercase_range(s, 0..s.len())
+	}
+
+	fn to_sentence_case(self, s: &str) -> String {
+		let initial_length = self.initial_length(s);
+		let initial = &s[..initial_length];
+		let mut chars = initial.chars();
+		let titlecase_initial = if self.starts_with_ij(s) {
+			String::from("IJ")
+		} else if let Some(titlecase) = chars.next().and_then(to_titlecase) {
+			let marks =
+				self.uppercase_range(s, initial.len() - chars.as_str().len()..initial_length);
+			format!("{}{}", titlecase, marks)
+		} else {
+			self.uppercase_range(s, 0..initial_length)
+		};
+		format!(
+			"{}{}",
+			titlecase_initial,
+			self.lowercase_range(s, initial_length..s.len())
+		)
+	}
+
+	/// Gets the length of the first letter, which is a grapheme or Dutch `ij`.
+	fn initial_length(self, s: &str) -> usize {
+		if self.starts_with_ij(s) {
+			2
+		} else {
+			s.graphemes(true).next().map_or(0, str::len)
+		}
+	}
+
+	fn starts_with_ij(self, s: &str) -> bool {
+		let bytes = s.as_bytes();
+		self == Self::Dutch
+			&& bytes.len() >= 2
+			&& bytes[0].eq_ignore_ascii_case(&b'i')
+			&& bytes[1].eq_ignore_ascii_case(&b'j')
+	}
+
+	/// Lowercases part of a string, looking at the characters around it for context.
+	fn lowercase_range(self, s: &str, range: Range<usize>) -> String {
+		let mut mapped = String::with_capacity(range.len());
+		let mut chars = s[range.clone()].char_indices();
+		while let Some((offset, c)) = chars.next() {
+			let before = &s[..range.start + offset];
+			let after = &s[range.start + offset + c.len_utf8()..];
+			if let Some((plain, style)) = style::unstyle(c) {
+				mapped.push(style.apply(plain.to_ascii_lowercase()).unwrap_or(c));
+			} else if c == 'Σ' {
+				mapped.push(if is_final_sigma(before, after) {
+					'ς'
+				} else {
+					'σ'
+				});
+			} else if self == Self::Turkish && c == 'İ' {
+				mapped.push('i');
+			} else if self == Self::Turkish && c == 'I' {
+				if after.starts_with(DOT_ABOVE) {
+					mapped.push('i');
+					chars.next();
+				} else {
+					mapped.push('ı');
+				}
+			} else if let (Self::Lithuanian, Some(accent)) = (self, lithuanian_accent(c)) {
+				mapped.extend(['i', DOT_ABOVE, accent]);
+			} else if self == Self::Lithuanian
+				&& matches!(c, 'I' | 'J' | 'Į')
+				&& is_followed_by_mark_above(after)
+			{
+				mapped.extend(c.to_lowercase());
+				mapped.push(DOT_ABOVE);
+			} else {
+				mapped.extend(c.to_lowercase());
+			}
+		}
+		mapped
+	}
+
+	/// Uppercases part of a string, looking at the characters before it for context.
+	fn uppercase_range(self, s: &str, range: Range<usize>) -> String {
+		let mut mapped = String::with_capacity(range.len());
+		for (offset, c) in s[range.clone()].char_indices() {
+			let before = &s[..range.start + offset];
+			if let Some((plain, style)) = style::unstyle(c) {
+				mapped.push(style.apply(plain.to_ascii_uppercase()).unwrap_or(c));
+			} else if self == Self::Turkish && c == 'i' {
+				mapped.push('İ');
+			} else if self == Self::German && c == 'ß' {
+				mapped.push('ẞ');
+			} else if self == Self::Lithuanian && c == DOT_ABOVE && is_after_soft_dotted(before) {
+				continue;
+			} else {
+				mapped.extend(c.to_uppercase());
+			}
+		}
+		mapped
+	}
+}
+
 /// Detects the case of a word.
 ///
 /// # Examples
@@ -73,6 +319,14 @@ impl fmt::Display for Case {
 /// assert_eq!(detect_case("Å"), Case::Sentence);
 /// ```
 ///
+/// Titlecase digraphs start sentence-case words.
+///
+/// ```
+/// # use porcus::case::{Case, detect_case};
+/// assert_eq!(detect_case("ǅungla"), Case::Sentence);
+/// assert_eq!(detect_case("ǄUNGLA"), Case::Upper);
+/// ```
+///
 /// An all-uncased string is considered lowercase.
 ///
 /// ```
@@ -90,19 +344,7 @@ impl fmt::Display for Case {
 /// ```
 #[must_use]
 pub fn detect_case(s: &str) -> Case {
-	s.chars().next().map_or(Case::Lower, |first_char| {
-		let first_is_lower = !first_char.is_uppercase();
-		let first_is_upper = !first_char.is_lowercase();
-		let rest_is_upper = s.chars().skip(1).all(|c| !c.is_lowercase());
-		let rest_is_lower = s.chars().skip(1).all(|c| !c.is_uppercase());
-
-		match (first_is_lower, first_is_upper, rest_is_lower, rest_is_upper) {
-			(true, _, true, _) => Case::Lower,
-			(_, true, true, _) => Case::Sentence,
-			(_, true, _, true) => Case::Upper,
-			_ => Case::Mixed,
-		}
-	})
+	Locale::Root.detect_case(s)
 }
 
 /// Returns the equivalent of a string as the specified case.
@@ -118,6 +360,21 @@ pub fn detect_case(s: &str) -> Case {
 /// assert_eq!(to_case("fooBAR", Case::Sentence), "Foobar");
 /// ```
 ///
+/// [Styled letters](crate::style) keep their style.
+///
+/// ```
+/// # use porcus::case::{Case, to_case};
+/// assert_eq!(to_case("𝐞𝐥𝐥𝐨𝐇", Case::Sentence), "𝐄𝐥𝐥𝐨𝐡");
+/// ```
+///
+/// Digraphs are capitalized in titlecase.
+///
+/// ```
+/// # use porcus::case::{Case, to_case};
+/// assert_eq!(to_case("ǆungla", Case::Sentence), "ǅungla");
+/// assert_eq!(to_case("ǈUDI", Case::Upper), "ǇUDI");
+/// ```
+///
 /// Conversion to mixed case leaves the string unchanged.
 ///
 /// ```
@@ -126,27 +383,67 @@ pub fn detect_case(s: &str) -> Case {
 /// assert_eq!(to_case("foobar", Case::Mixed), "foobar");
 /// ```
 pub fn to_case<S: Into<String>>(s: S, case: Case) -> String {
-	let s = s.into();
-	match case {
-		Case::Lower => s.to_lowercase(),
-		Case::Upper => s.to_uppercase(),
-		Case::Sentence => to_sentence_case(&s),
-		Case::Mixed => s,
+	Locale::Root.to_case(s, case)
+}
+
+fn is_titlecase(c: char) -> bool {
+	matches!(c, 'ǅ' | 'ǈ' | 'ǋ' | 'ǲ')
+}
+
+fn to_titlecase(c: char) -> Option<char> {
+	match c {
+		'Ǆ' | 'ǅ' | 'ǆ' => Some('ǅ'),
+		'Ǉ' | 'ǈ' | 'ǉ' => Some('ǈ'),
+		'Ǌ' | 'ǋ' | 'ǌ' => Some('ǋ'),
+		'Ǳ' | 'ǲ' | 'ǳ' => Some('ǲ'),
+		_ => None,
 	}
 }
 
-fn to_sentence_case(s: &str) -> String {
-	let mut graphemes = s.graphemes(true);
-	let first = graphemes.next();
+fn is_cased(c: char) -> bool {
+	c.is_lowercase() || c.is_uppercase() || is_titlecase(c)
+}
+
+fn is_case_ignorable(c: char) -> bool {
+	is_combining_mark(c) || CASE_IGNORABLE.contains(&c)
+}
 
-	if let Some(first_grapheme) = first {
-		let rest = graphemes.as_str();
-		format!("{}{}", first_grapheme.to_uppercase(), rest.to_lowercase())
-	} else {
-		String::new()
+/// Checks whether a sigma ends a word, i.e. follows a letter but does not precede one.
+fn is_final_sigma(before: &str, after: &str) -> bool {
+	let mut previous = before.chars().rev().filter(|c| !is_case_ignorable(*c));
+	let mut next = after.chars().filter(|c| !is_case_ignorable(*c));
+	previous.next().is_some_and(is_cased) && !next.next().is_some_and(is_cased)
+}
+
+/// Gets the accent of a precomposed Lithuanian `i` which needs an explicit dot when lowercase.
+fn lithuanian_accent(c: char) -> Option<char> {
+	match c {
+		'Ì' => Some('\u{300}'),
+		'Í' => Some('\u{301}'),
+		'Ĩ' => Some('\u{303}'),
+		_ => None,
 	}
 }
 
+/// Checks whether a mark above follows, with no other mark above or base letter in between.
+fn is_followed_by_mark_above(after: &str) -> bool {
+	after
+		.chars()
+		.map(canonical_combining_class)
+		.take_while(|class| *class != 0)
+		.find(|class| *class == ABOVE)
+		.is_some()
+}
+
+/// Checks whether a mark follows a soft-dotted letter, with no other mark above in between.
+fn is_after_soft_dotted(before: &str) -> bool {
+	before
+		.chars()
+		.rev()
+		.find(|c| matches!(canonical_combining_class(*c), 0 | ABOVE))
+		.is_some_and(|c| SOFT_DOTTED.contains(&c))
+}
+
 #[cfg(test)]
 mod test_detect_case {
 	use super::*;
@@ -227,3 +524,74 @@ mod test_to_case {
 		assert_case_transform("测试", Case::Mixed, "测试");
 	}
 }
+
+#[cfg(test)]
+mod test_locale {
+	use super::*;
+
+	#[test]
+	fn styled() {
+		assert_eq!(to_case("🄷🄸", Case::Lower), "🄷🄸");
+		assert_eq!(to_case("🅗🅘", Case::Upper), "🅗🅘");
+		assert_eq!(to_case("ⓗⓘ", Case::Sentence), "Ⓗⓘ");
+	}
+
+	#[test]
+	fn final_sigma() {
+		assert_eq!(to_case("ΟΣ", Case::Sentence), "Ος");
+		assert_eq!(to_case("ΣΑΣ'", Case::Lower), "σας'");
+		assert_eq!(to_case("ΣΑΣ𝐀", Case::Lower), "σασ𝐚");
+		assert_eq!(to_case("Σ", Case::Lower), "σ");
+	}
+
+	#[test]
+	fn titlecase_digraphs() {
+		assert_eq!(to_case("ǄEP", Case::Sentence), "ǅep");
+		assert_eq!(to_case("ǉudi", Case::Sentence), "ǈudi");
+		assert_eq!(to_case("ǋegoš", Case::Lower), "ǌegoš");
+		assert_eq!(to_case("ǳ", Case::Sentence), "ǲ");
+		assert_eq!(detect_case("ǈ"), Case::Sentence);
+		assert_eq!(detect_case("ǅUNGLA"), Case::Upper);
+	}
+
+	#[test]
+	fn turkish() {
+		let locale = Locale::Turkish;
+		assert_eq!(locale.to_case("ISPARTA", Case::Lower), "ısparta");
+		assert_eq!(locale.to_case("İZMİR", Case::Sentence), "İzmir");
+		assert_eq!(locale.to_case("I\u{307}zmir", Case::Lower), "izmir");
+		assert_eq!(locale.to_case("ığdır", Case::Upper), "IĞDIR");
+		assert_eq!(Locale::Root.to_case("istanbul", Case::Upper), "ISTANBUL");
+	}
+
+	#[test]
+	fn lithuanian() {
+		let locale = Locale::Lithuanian;
+		assert_eq!(locale.to_case("ÌS", Case::Lower), "i\u{307}\u{300}s");
+		assert_eq!(locale.to_case("I\u{301}", Case::Lower), "i\u{307}\u{301}");
+		assert_eq!(locale.to_case("Į\u{303}", Case::Lower), "į\u{307}\u{303}");
+		assert_eq!(locale.to_case("i\u{307}\u{300}s", Case::Upper), "I\u{300}S");
+		assert_eq!(locale.to_case("IS", Case::Lower), "is");
+		assert_eq!(Locale::Root.to_case("Ì", Case::Lower), "ì");
+	}
+
+	#[test]
+	fn dutch() {
+		let locale = Locale::Dutch;
+		assert_eq!(locale.to_case("ijsland", Case::Sentence), "IJsland");
+		assert_eq!(locale.to_case("IJSLAND", Case::Sentence), "IJsland");
+		assert_eq!(locale.to_case("ĳs", Case::Sentence), "Ĳs");
+		assert_eq!(locale.to_case("ik", Case::Sentence), "Ik");
+		assert_eq!(locale.detect_case("IJ"), Case::Sentence);
+		assert_eq!(locale.detect_case("IJSLAND"), Case::Upper);
+		assert_eq!(locale.detect_case("Ijsland"), Case::Mixed);
+	}
+
+	#[test]
+	fn german() {
+		let locale = Locale::German;
+		assert_eq!(locale.to_case("grüße", Case::Upper), "GRÜẞE");
+		assert_eq!(locale.to_case("GRÜẞE", Case::Sentence), "Grüße");
+		assert_eq!(Locale::Root.to_case("grüße", Case::Upper), "GRÜSSE");
+	}
+}
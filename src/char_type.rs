@@ -5,7 +5,7 @@
 //! ```
 //! use porcus::char_type::{CharType, get_char_type_at};
 //!
-//! assert_eq!(get_char_type_at(&vec!["ʃ"], 0), CharType::Consonant);
+//! assert_eq!(get_char_type_at(&["ʃ"], 0), CharType::Consonant);
 //! ```
 //!
 //! # Classification details
@@ -19,12 +19,26 @@
 //! The empty string also receives [its own special classification](CharType::Empty).
 
 use crate::latin::{AMBIGUOUS_VOWELS, CONSONANT_LIKE_PUNCTUATION, VOWELS};
-use std::fmt;
+use crate::style;
+use core::fmt;
 use unicode_normalization::UnicodeNormalization;
 use unicode_script::UnicodeScript;
 
 /// Vowel-or-consonant classification of a grapheme.
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[cfg_attr(
+	feature = "python",
+	pyo3::pyclass(
+		eq,
+		eq_int,
+		from_py_object,
+		frozen,
+		hash,
+		str,
+		module = "porcus",
+		rename_all = "SCREAMING_SNAKE_CASE"
+	)
+)]
 pub enum CharType {
 	/// Latin vowel, e.g. `A`, `æ`, `ő`, `ɛ`.
 	Vowel,
@@ -53,6 +67,32 @@ impl fmt::Display for CharType {
 	}
 }
 
+/// Classification of ASCII and Latin-1 characters, by code point.
+///
+/// It matches the general classification, as checked by tests.
+#[rustfmt::skip]
+const LATIN_1: [CharType; 256] = {
+	use CharType::{Ambiguous as A, Consonant as C, NonLatin as N, Vowel as V};
+	[
+		N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, // U+0000
+		N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, // U+0010
+		N, N, N, N, N, N, N, C, N, N, N, N, N, N, N, N, // U+0020
+		N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, // U+0030
+		N, V, C, C, C, V, C, C, C, V, C, C, C, C, C, V, // U+0040
+		C, C, C, C, C, V, C, C, C, A, C, N, N, N, N, N, // U+0050
+		N, V, C, C, C, V, C, C, C, V, C, C, C, C, C, V, // U+0060
+		C, C, C, C, C, V, C, C, C, A, C, N, N, N, N, N, // U+0070
+		N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, // U+0080
+		N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, // U+0090
+		N, N, N, N, N, N, N, N, N, N, V, N, N, N, N, N, // U+00A0
+		N, N, N, N, N, N, N, C, N, N, V, N, N, N, N, N, // U+00B0
+		V, V, V, V, V, V, V, C, V, V, V, V, V, V, V, V, // U+00C0
+		C, C, V, V, V, V, V, N, V, V, V, V, V, A, C, C, // U+00D0
+		V, V, V, V, V, V, V, C, V, V, V, V, V, V, V, V, // U+00E0
+		C, C, V, V, V, V, V, N, V, V, V, V, V, A, C, A, // U+00F0
+	]
+};
+
 /// Classifies the grapheme at the specified index as a vowel or a consonant.
 ///
 /// # Examples
@@ -61,7 +101,7 @@ impl fmt::Display for CharType {
 ///
 /// ```
 /// # use porcus::char_type::{CharType, get_char_type_at};
-/// let v = &vec!["B", "a", "y", "."];
+/// let v = &["B", "a", "y", "."];
 /// assert_eq!(get_char_type_at(v, 0), CharType::Consonant);
 /// assert_eq!(get_char_type_at(v, 1), CharType::Vowel);
 /// assert_eq!(get_char_type_at(v, 2), CharType::Ambiguous);
@@ -73,7 +113,7 @@ impl fmt::Display for CharType {
 ///
 /// ```
 /// # use porcus::char_type::{CharType, get_char_type_at};
-/// let v = &vec!["'", "״"];
+/// let v = &["'", "״"];
 /// assert_eq!(get_char_type_at(v, 0), CharType::Consonant);
 /// assert_eq!(get_char_type_at(v, 1), CharType::Consonant);
 /// ```
@@ -82,11 +122,21 @@ impl fmt::Display for CharType {
 ///
 /// ```
 /// # use porcus::char_type::{CharType, get_char_type_at};
-/// let v = &vec!["ç", "c\u{0327}"];
+/// let v = &["ç", "c\u{0327}"];
 /// assert_eq!(get_char_type_at(v, 0), CharType::Consonant);
 /// assert_eq!(get_char_type_at(v, 1), CharType::Consonant);
 /// ```
 ///
+/// [Styled letters](crate::style) are classified by their compatibility decomposition.
+///
+/// ```
+/// # use porcus::char_type::{CharType, get_char_type_at};
+/// let v = &["𝐇", "ⓔ", "🄻"];
+/// assert_eq!(get_char_type_at(v, 0), CharType::Consonant);
+/// assert_eq!(get_char_type_at(v, 1), CharType::Vowel);
+/// assert_eq!(get_char_type_at(v, 2), CharType::Consonant);
+/// ```
+///
 /// This function expects its first argument to contain single grapheme clusters as returned by
 /// [`UnicodeSegmentation::graphemes`](https://unicode-rs.github.io/unicode-segmentation/unicode_segmentation/trait.UnicodeSegmentation.html#tymethod.graphemes),
 /// but will classify the first grapheme of any string, and return a
@@ -94,7 +144,7 @@ impl fmt::Display for CharType {
 ///
 /// ```
 /// # use porcus::char_type::{CharType, get_char_type_at};
-/// let v = &vec!["", "abc"];
+/// let v = &["", "abc"];
 /// assert_eq!(get_char_type_at(v, 0), CharType::Empty);
 /// assert_eq!(get_char_type_at(v, 1), CharType::Vowel);
 /// ```
@@ -105,7 +155,7 @@ impl fmt::Display for CharType {
 ///
 /// ```
 /// # use porcus::char_type::{CharType, get_char_type_at};
-/// let v = &vec!["α", "ב"];
+/// let v = &["α", "ב"];
 /// assert_eq!(get_char_type_at(v, 0), CharType::NonLatin);
 /// assert_eq!(get_char_type_at(v, 1), CharType::NonLatin);
 /// ```
@@ -116,34 +166,48 @@ impl fmt::Display for CharType {
 ///
 /// ```
 /// # use porcus::char_type::{CharType, get_char_type_at};
-/// let v = &vec!["w"]; // a Welsh vowel
+/// let v = &["w"]; // a Welsh vowel
 /// assert_eq!(get_char_type_at(v, 0), CharType::Consonant);
 /// ```
 #[must_use]
 pub fn get_char_type_at(graphemes: &[&str], index: usize) -> CharType {
-	get_first_nfd_char_of_grapheme_at(graphemes, index).map_or(CharType::Empty, |first_char| {
-		if VOWELS.contains(&first_char) {
-			return CharType::Vowel;
-		}
-		if AMBIGUOUS_VOWELS.contains(&first_char) {
-			return CharType::Ambiguous;
-		}
-		if CONSONANT_LIKE_PUNCTUATION.contains(&first_char) {
-			return CharType::Consonant;
-		}
-		let script = first_char.script().full_name();
-		if script == "Latin" {
-			CharType::Consonant
-		} else {
-			CharType::NonLatin
+	// Latin-1 characters start their own decomposition, so combining marks after them can be
+	// ignored.
+	match graphemes
+		.get(index)
+		.and_then(|grapheme| grapheme.chars().next())
+	{
+		None => CharType::Empty,
+		Some(first_char) if u32::from(first_char) < 0x100 => LATIN_1[first_char as usize],
+		Some(_) => {
+			get_first_nfd_char_of_grapheme_at(graphemes, index).map_or(CharType::Empty, classify)
 		}
-	})
+	}
+}
+
+fn classify(first_char: char) -> CharType {
+	if VOWELS.contains(&first_char) {
+		return CharType::Vowel;
+	}
+	if AMBIGUOUS_VOWELS.contains(&first_char) {
+		return CharType::Ambiguous;
+	}
+	if CONSONANT_LIKE_PUNCTUATION.contains(&first_char) {
+		return CharType::Consonant;
+	}
+	let script = first_char.script().full_name();
+	if script == "Latin" {
+		CharType::Consonant
+	} else {
+		CharType::NonLatin
+	}
 }
 
 fn get_first_nfd_char_of_grapheme_at(graphemes: &[&str], index: usize) -> Option<char> {
 	graphemes
 		.get(index)
 		.and_then(|grapheme| grapheme.nfd().next())
+		.map(|first_char| style::unstyle(first_char).map_or(first_char, |(plain, _)| plain))
 }
 
 #[cfg(test)]
@@ -152,14 +216,14 @@ mod test_get_char_type_at {
 
 	#[test]
 	fn empty() {
-		assert_eq!(get_char_type_at(&vec![], 0), CharType::Empty);
-		assert_eq!(get_char_type_at(&vec![""], 0), CharType::Empty);
-		assert_eq!(get_char_type_at(&vec!["a"], 42), CharType::Empty);
+		assert_eq!(get_char_type_at(&[], 0), CharType::Empty);
+		assert_eq!(get_char_type_at(&[""], 0), CharType::Empty);
+		assert_eq!(get_char_type_at(&["a"], 42), CharType::Empty);
 	}
 
 	#[test]
 	fn vowels() {
-		let graphemes = &vec![
+		let graphemes = &[
 			"a", "e", "i", "o", "u", "A", "å", "ã", "é", "Î", "ö", "ø", "œ", "ə",
 		];
 		for grapheme_index in 0..graphemes.len() {
@@ -169,7 +233,7 @@ mod test_get_char_type_at {
 
 	#[test]
 	fn consonants() {
-		let graphemes = &vec!["b", "B", "ç", "Đ", "þ", "ñ", "ß", "ʔ", "Ⅰ"];
+		let graphemes = &["b", "B", "ç", "Đ", "þ", "ñ", "ß", "ʔ", "Ⅰ"];
 		for grapheme_index in 0..graphemes.len() {
 			assert_eq!(
 				get_char_type_at(graphemes, grapheme_index),
@@ -180,7 +244,7 @@ mod test_get_char_type_at {
 
 	#[test]
 	fn ambiguous() {
-		let graphemes = &vec!["y", "Y", "Ÿ", "ȳ", "ỿ", "Ｙ"];
+		let graphemes = &["y", "Y", "Ÿ", "ȳ", "ỿ", "Ｙ"];
 		for grapheme_index in 0..graphemes.len() {
 			assert_eq!(
 				get_char_type_at(graphemes, grapheme_index),
@@ -191,7 +255,7 @@ mod test_get_char_type_at {
 
 	#[test]
 	fn non_latin() {
-		let graphemes = &vec![" ", "\"", ",", ".", "π"];
+		let graphemes = &[" ", "\"", ",", ".", "π"];
 		for grapheme_index in 0..graphemes.len() {
 			assert_eq!(
 				get_char_type_at(graphemes, grapheme_index),
@@ -202,7 +266,29 @@ mod test_get_char_type_at {
 
 	#[test]
 	fn treat_special_punctuation_as_consonants() {
-		let graphemes = &vec!["'", "’", "·", "״"];
+		let graphemes = &["'", "’", "·", "״"];
+		for grapheme_index in 0..graphemes.len() {
+			assert_eq!(
+				get_char_type_at(graphemes, grapheme_index),
+				CharType::Consonant
+			);
+		}
+	}
+
+	#[test]
+	fn styled() {
+		let graphemes = &["𝐀", "𝒆", "ℯ", "Ⓘ", "🅾", "𝕦"];
+		for grapheme_index in 0..graphemes.len() {
+			assert_eq!(get_char_type_at(graphemes, grapheme_index), CharType::Vowel);
+		}
+		let graphemes = &["𝐲", "ⓨ", "🅈"];
+		for grapheme_index in 0..graphemes.len() {
+			assert_eq!(
+				get_char_type_at(graphemes, grapheme_index),
+				CharType::Ambiguous
+			);
+		}
+		let graphemes = &["𝐁", "ℬ", "ⓒ", "🄳", "𝚣"];
 		for grapheme_index in 0..graphemes.len() {
 			assert_eq!(
 				get_char_type_at(graphemes, grapheme_index),
@@ -213,7 +299,7 @@ mod test_get_char_type_at {
 
 	#[test]
 	fn treat_modifiers_as_consonants() {
-		let graphemes = &vec!["ʰ", "ᵃ", "ʸ"];
+		let graphemes = &["ʰ", "ᵃ", "ʸ"];
 		for grapheme_index in 0..graphemes.len() {
 			assert_eq!(
 				get_char_type_at(graphemes, grapheme_index),
@@ -222,3 +308,33 @@ mod test_get_char_type_at {
 		}
 	}
 }
+
+#[cfg(test)]
+mod test_latin_1 {
+	use super::*;
+
+	/// Classifies a grapheme without the Latin-1 table.
+	fn classify_grapheme(grapheme: &str) -> CharType {
+		get_first_nfd_char_of_grapheme_at(&[grapheme], 0).map_or(CharType::Empty, classify)
+	}
+
+	#[test]
+	fn table_matches_general_classification() {
+		for code_point in 0..=0xff_u32 {
+			let c = char::from_u32(code_point).unwrap();
+			for grapheme in [
+				c.to_string(),
+				format!("{}\u{301}", c),
+				format!("{}\u{327}\u{301}", c),
+				format!("{}\u{200d}", c),
+			] {
+				assert_eq!(
+					get_char_type_at(&[grapheme.as_str()], 0),
+					classify_grapheme(&grapheme),
+					"{:?}",
+					grapheme
+				);
+			}
+		}
+	}
+}
@@ -0,0 +1,299 @@
+//! Configuration files and named profiles.
+//!
+//! A configuration file holds profiles as TOML tables:
+//!
+//! ```toml
+//! [profiles.default]
+//! orthography = "english"
+//!
+//! [profiles.kids]
+//! vowel-suffix = "yay"
+//! dialect = "playground"
+//! format = "markdown"
+//!
+//! [profiles.kids.exceptions]
+//! keep = ["NASA"]
+//! replace = { GitHub = "ItHubgay" }
+//! ```
+//!
+//! Libraries using the `serde` feature can load a profile as a `PigLatinTransformer` if it has
+//! no command-line settings: `lexicon`, `ipa`, `format` and `encoding` are rejected, like unknown
+//! settings.
+//!
+//! Suffixes take the width or style of the word they join, e.g. fullwidth, so profiles have no
+//! suffixes per script.
+
+use super::error::CliError;
+use porcus::acronym::AcronymPolicy;
+use porcus::case::Locale;
+use porcus::compound::{Contraction, Elision, Hyphenation};
+use porcus::dialect::Dialect;
+use porcus::encoding::Encoding;
+use porcus::exceptions::Exceptions;
+use porcus::format::Format;
+use porcus::normalization::Normalization;
+use porcus::number::Language;
+use porcus::orthography::Orthography;
+use porcus::token::TokenPolicy;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of configuration files.
+pub const CONFIG_FILE_NAME: &str = "porcus.toml";
+
+/// Profile used when none is selected.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Contents of a configuration file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+	/// Profiles by name.
+	pub profiles: BTreeMap<String, Profile>,
+}
+
+/// Named set of options. Options given on the command line take precedence.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Profile {
+	/// Suffix for words starting with a consonant.
+	pub consonant_suffix: Option<String>,
+	/// Suffix for words starting with a vowel.
+	pub vowel_suffix: Option<String>,
+	/// Rule variant, as a preset name or a table of rules.
+	pub dialect: Option<Dialect>,
+	/// Spelling conventions.
+	pub orthography: Option<Orthography>,
+	/// How acronyms are translated.
+	pub acronym_policy: Option<AcronymPolicy>,
+	/// How URLs, emails and other atomic tokens are translated.
+	pub token_policy: Option<TokenPolicy>,
+	/// Language rules for matching case.
+	pub locale: Option<Locale>,
+	/// Whether to repair capitals across sentences.
+	pub sentence_capitalization: bool,
+	/// Unicode normalization form of the output.
+	pub normalization: Option<Normalization>,
+	/// How hyphenated compounds are translated.
+	pub hyphenation: Option<Hyphenation>,
+	/// How elided words are translated.
+	pub elision: Option<Elision>,
+	/// How English contractions are translated.
+	pub contraction: Option<Contraction>,
+	/// Language numbers are spelled out in.
+	pub numbers: Option<Language>,
+	/// Words with a fixed translation.
+	pub exceptions: Exceptions,
+	/// Pronunciation dictionary, relative to the configuration file.
+	pub lexicon: Option<PathBuf>,
+	/// Whether to output IPA.
+	pub ipa: bool,
+	/// Markup format.
+	pub format: Option<Format>,
+	/// Character encoding.
+	pub encoding: Option<Encoding>,
+}
+
+impl Config {
+	/// Parses a configuration, resolving relative paths against a directory.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the configuration is not valid TOML or has unknown settings.
+	pub fn parse(text: &str, directory: &Path) -> Result<Self, toml::de::Error> {
+		let mut config: Self = toml::from_str(text)?;
+		for profile in config.profiles.values_mut() {
+			if let Some(lexicon) = &mut profile.lexicon {
+				*lexicon = directory.join(&lexicon);
+			}
+		}
+		Ok(config)
+	}
+
+	/// Reads a configuration file.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the file cannot be read or parsed.
+	pub fn read(path: &Path) -> Result<Self, CliError> {
+		let text = fs::read_to_string(path).map_err(|error| CliError::io(path, error))?;
+		let directory = path.parent().unwrap_or_else(|| Path::new(""));
+		Self::parse(&text, directory).map_err(|source| CliError::Config {
+			path: path.to_path_buf(),
+			source,
+		})
+	}
+
+	/// Gets a profile by name, or the default profile if no name is given.
+	///
+	/// A missing default profile has no settings.
+	///
+	/// # Errors
+	///
+	/// Returns an error if a named profile does not exist.
+	pub fn profile(&self, name: Option<&str>) -> Result<Profile, CliError> {
+		match name {
+			Some(name) => self
+				.profiles
+				.get(name)
+				.cloned()
+				.ok_or_else(|| CliError::UnknownProfile(name.to_string())),
+			None => Ok(self
+				.profiles
+				.get(DEFAULT_PROFILE)
+				.cloned()
+				.unwrap_or_default()),
+		}
+	}
+}
+
+/// Finds the configuration file.
+///
+/// Looks for `porcus.toml` in the current directory and its ancestors, then in the `porcus`
+/// directory of the XDG configuration directory.
+pub fn find() -> Option<PathBuf> {
+	let project = env::current_dir().ok().and_then(|directory| {
+		directory
+			.ancestors()
+			.map(|ancestor| ancestor.join(CONFIG_FILE_NAME))
+			.find(|path| path.is_file())
+	});
+	project.or_else(|| {
+		let config_home = env::var_os("XDG_CONFIG_HOME")
+			.filter(|home| !home.is_empty())
+			.map(PathBuf::from)
+			.or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+		Some(config_home.join("porcus").join(CONFIG_FILE_NAME)).filter(|path| path.is_file())
+	})
+}
+
+#[cfg(test)]
+mod test_config {
+	use super::*;
+
+	#[test]
+	fn profiles() {
+		let config = Config::parse(
+			r#"
+				[profiles.default]
+				orthography = "english"
+
+				[profiles.kids]
+				vowel-suffix = "yay"
+				dialect = "playground"
+				acronym-policy = "spell"
+				token-policy = "transform-hashtags"
+				locale = "dutch"
+				sentence-capitalization = true
+				normalization = "nfc"
+				hyphenation = "whole"
+				elision = "attach"
+				contraction = "keep-clitic"
+				numbers = "french"
+				lexicon = "dict/cmudict.dict"
+				ipa = true
+				format = "markdown"
+				encoding = "windows-1252"
+
+				[profiles.kids.exceptions]
+				keep = ["NASA"]
+			"#,
+			Path::new("/home/me"),
+		)
+		.unwrap();
+
+		let default = config.profile(None).unwrap();
+		assert_eq!(default.orthography, Some(Orthography::English));
+		assert_eq!(default.dialect, None);
+
+		let kids = config.profile(Some("kids")).unwrap();
+		assert_eq!(kids.consonant_suffix, None);
+		assert_eq!(kids.vowel_suffix.as_deref(), Some("yay"));
+		assert_eq!(kids.dialect, Dialect::from_preset("playground"));
+		assert_eq!(kids.acronym_policy, Some(AcronymPolicy::Spell));
+		assert_eq!(kids.token_policy, Some(TokenPolicy::TransformHashtags));
+		assert_eq!(kids.locale, Some(Locale::Dutch));
+		assert!(kids.sentence_capitalization);
+		assert_eq!(kids.normalization, Some(Normalization::Nfc));
+		assert_eq!(kids.hyphenation, Some(Hyphenation::Whole));
+		assert_eq!(kids.elision, Some(Elision::Attach));
+		assert_eq!(kids.contraction, Some(Contraction::KeepClitic));
+		assert_eq!(kids.numbers, Some(Language::French));
+		assert_eq!(
+			kids.lexicon,
+			Some(PathBuf::from("/home/me/dict/cmudict.dict"))
+		);
+		assert!(kids.ipa);
+		assert_eq!(kids.format, Some(Format::Markdown));
+		assert_eq!(kids.encoding, Some(Encoding::Windows1252));
+		assert_eq!(kids.exceptions, Exceptions::new().with_kept("nasa"));
+	}
+
+	#[test]
+	fn library_profiles() {
+		let profile = r#"
+			vowel-suffix = "yay"
+			dialect = "qu-cluster"
+			sentence-capitalization = true
+			hyphenation = "whole"
+			elision = "attach"
+			contraction = "keep-clitic"
+			exceptions = { keep = ["NASA"] }
+		"#;
+		let config =
+			Config::parse(&format!("[profiles.kids]\n{}", profile), Path::new("")).unwrap();
+		let kids = config.profile(Some("kids")).unwrap();
+		let transformer = toml::from_str::<porcus::PigLatinTransformer>(profile).unwrap();
+		assert_eq!(
+			transformer,
+			porcus::PigLatinTransformer::default()
+				.with_vowel_suffix(kids.vowel_suffix.unwrap())
+				.with_dialect(kids.dialect.unwrap())
+				.with_sentence_capitalization(kids.sentence_capitalization)
+				.with_hyphenation(kids.hyphenation.unwrap())
+				.with_elision(kids.elision.unwrap())
+				.with_contraction(kids.contraction.unwrap())
+				.with_exceptions(kids.exceptions)
+		);
+		assert_eq!(
+			transformer.to_pig_latin("NASA's T-shirt"),
+			"NASA's Irtt-shay"
+		);
+
+		for setting in ["lexicon = \"a.dict\"", "ipa = true", "format = \"html\""] {
+			assert!(Config::parse(&format!("[profiles.kids]\n{}", setting), Path::new("")).is_ok());
+			assert!(
+				toml::from_str::<porcus::PigLatinTransformer>(setting).is_err(),
+				"{}",
+				setting
+			);
+		}
+	}
+
+	#[test]
+	fn missing_profiles() {
+		let config = Config::parse("", Path::new("")).unwrap();
+		assert!(config.profile(None).unwrap().dialect.is_none());
+		assert!(matches!(
+			config.profile(Some("kids")),
+			Err(CliError::UnknownProfile(_))
+		));
+	}
+
+	#[test]
+	fn invalid() {
+		let invalid = [
+			"profiles = 3",
+			"[profiles.kids]\nvowel = \"yay\"",
+			"[profiles.kids]\nhyphens = \"whole\"",
+			"[profiles.kids]\ndialect = \"klingon\"",
+			"[other]",
+		];
+		for text in invalid {
+			assert!(Config::parse(text, Path::new("")).is_err(), "{}", text);
+		}
+	}
+}
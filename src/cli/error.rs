@@ -0,0 +1,99 @@
+//! Errors reported by the command-line interface.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Error while processing one input or setting up the run.
+#[derive(Debug)]
+pub enum CliError {
+	/// Reading or writing a file failed.
+	Io {
+		/// File being read or written, or `None` for standard streams.
+		path: Option<PathBuf>,
+		/// Underlying error.
+		source: io::Error,
+	},
+	/// A configuration file is invalid.
+	Config {
+		/// Configuration file.
+		path: PathBuf,
+		/// Underlying error.
+		source: toml::de::Error,
+	},
+	/// The selected profile does not exist.
+	UnknownProfile(String),
+	/// IPA output was requested without a pronunciation dictionary.
+	IpaWithoutLexicon,
+	/// An include or exclude pattern is invalid.
+	Glob(globset::Error),
+	/// Standard input cannot be edited in place.
+	InPlaceStdin,
+	/// The output file is also an input.
+	OutputIsInput(PathBuf),
+	/// Threads for parallel jobs cannot be started.
+	ThreadPool(rayon::ThreadPoolBuildError),
+}
+
+impl CliError {
+	/// Wraps an I/O error on a file.
+	pub fn io<P: Into<PathBuf>>(path: P, source: io::Error) -> Self {
+		Self::Io {
+			path: Some(path.into()),
+			source,
+		}
+	}
+
+	/// Wraps an I/O error on standard input or output.
+	pub const fn stdio(source: io::Error) -> Self {
+		Self::Io { path: None, source }
+	}
+
+	/// Checks whether the error is a closed output pipe, which ends the run quietly.
+	pub fn is_broken_pipe(&self) -> bool {
+		matches!(self, Self::Io { source, .. } if source.kind() == io::ErrorKind::BrokenPipe)
+	}
+}
+
+impl fmt::Display for CliError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Io {
+				path: Some(path),
+				source,
+			} => write!(f, "{}: {}", path.display(), source),
+			Self::Io { path: None, source } => write!(f, "{}", source),
+			Self::Config { path, source } => write!(f, "{}: {}", path.display(), source),
+			Self::UnknownProfile(name) => write!(f, "no profile named `{}`", name),
+			Self::IpaWithoutLexicon => f.write_str("IPA output requires a lexicon"),
+			Self::Glob(error) => write!(f, "{}", error),
+			Self::InPlaceStdin => f.write_str("cannot edit standard input in place"),
+			Self::OutputIsInput(path) => {
+				write!(f, "{}: output file is also an input", path.display())
+			}
+			Self::ThreadPool(error) => write!(f, "cannot start jobs: {}", error),
+		}
+	}
+}
+
+impl Error for CliError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Io { source, .. } => Some(source),
+			Self::Config { source, .. } => Some(source),
+			Self::Glob(error) => Some(error),
+			Self::ThreadPool(error) => Some(error),
+			Self::UnknownProfile(_)
+			| Self::IpaWithoutLexicon
+			| Self::InPlaceStdin
+			| Self::OutputIsInput(_) => None,
+		}
+	}
+}
+
+impl From<globset::Error> for CliError {
+	fn from(error: globset::Error) -> Self {
+		Self::Glob(error)
+	}
+}
@@ -0,0 +1,485 @@
+//! Reading inputs and writing outputs.
+
+use super::error::CliError;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use porcus::encoding::{self, Encoding};
+use porcus::format::Format;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Shortest piece of text transformed on its own thread.
+const MIN_CHUNK_LENGTH: usize = 16 * 1024;
+
+/// Pieces of text per thread, so that threads finishing early can take more.
+const CHUNKS_PER_JOB: usize = 4;
+
+/// Length of standard input read before transforming it on several threads.
+const STDIN_BATCH_LENGTH: usize = 4 * 1024 * 1024;
+
+/// Source of text to transform.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Input {
+	/// Standard input.
+	Stdin,
+	/// File on disk.
+	File(PathBuf),
+}
+
+/// Where transformed text is written.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Destination {
+	/// Standard output.
+	Stdout,
+	/// Single file receiving all outputs in order.
+	File(PathBuf),
+	/// Each input file is overwritten, after being copied to a backup with the suffix if any.
+	InPlace(Option<String>),
+}
+
+/// Include and exclude patterns for files found in directories.
+pub struct Filter {
+	include: Option<GlobSet>,
+	exclude: GlobSet,
+}
+
+impl Filter {
+	/// Builds a filter. With no include patterns, all files are included.
+	///
+	/// # Errors
+	///
+	/// Returns an error if a pattern is invalid.
+	pub fn new(include: &[&str], exclude: &[&str]) -> Result<Self, CliError> {
+		let build = |patterns: &[&str]| -> Result<GlobSet, CliError> {
+			let mut builder = GlobSetBuilder::new();
+			for pattern in patterns {
+				builder.add(Glob::new(pattern)?);
+			}
+			Ok(builder.build()?)
+		};
+		Ok(Self {
+			include: if include.is_empty() {
+				None
+			} else {
+				Some(build(include)?)
+			},
+			exclude: build(exclude)?,
+		})
+	}
+
+	/// Checks a path relative to the directory being searched.
+	fn accepts(&self, relative_path: &Path) -> bool {
+		let file_name = relative_path.file_name().map(Path::new);
+		let matches = |set: &GlobSet| {
+			set.is_match(relative_path) || file_name.is_some_and(|name| set.is_match(name))
+		};
+		self.include.as_ref().is_none_or(matches) && !matches(&self.exclude)
+	}
+}
+
+/// Transformation applied to each input.
+pub struct Processor<'a> {
+	/// Function transforming prose.
+	pub transform: &'a (dyn Fn(&str) -> String + Sync),
+	/// Markup format, or `None` to pick it from each file's extension.
+	pub format: Option<Format>,
+	/// Character encoding, or `None` to detect it from each input's byte order mark.
+	pub encoding: Option<Encoding>,
+	/// Number of threads large inputs are transformed on.
+	pub jobs: usize,
+}
+
+impl Processor<'_> {
+	fn format_of(&self, input: &Input) -> Format {
+		self.format.unwrap_or_else(|| match input {
+			Input::Stdin => Format::Plain,
+			Input::File(path) => path
+				.extension()
+				.and_then(|extension| extension.to_str())
+				.map_or(Format::Plain, Format::from_extension),
+		})
+	}
+
+	/// Transforms the prose of a text. With several jobs, the prose is split after line breaks,
+	/// which also end words and sentences, and the pieces are transformed in parallel.
+	fn process(&self, text: &str, format: Format) -> String {
+		if self.jobs <= 1 || text.len() < 2 * MIN_CHUNK_LENGTH {
+			return format.map_prose(text, self.transform);
+		}
+		let chunk_length = (text.len() / (self.jobs * CHUNKS_PER_JOB)).max(MIN_CHUNK_LENGTH);
+		format
+			.segments(text)
+			.into_iter()
+			.flat_map(|(segment, is_prose)| {
+				if is_prose {
+					split_lines(segment, chunk_length)
+						.into_iter()
+						.map(|chunk| (chunk, true))
+						.collect()
+				} else {
+					vec![(segment, false)]
+				}
+			})
+			.collect::<Vec<(&str, bool)>>()
+			.into_par_iter()
+			.map(|(chunk, is_prose)| {
+				if is_prose {
+					(self.transform)(chunk)
+				} else {
+					chunk.to_string()
+				}
+			})
+			.collect::<Vec<String>>()
+			.concat()
+	}
+
+	fn encoding_of(&self, bytes: &[u8]) -> Encoding {
+		self.encoding.unwrap_or_else(|| Encoding::detect(bytes))
+	}
+
+	/// Transforms bytes, passing invalid UTF-8 through unchanged and keeping legacy encodings.
+	fn process_bytes(&self, bytes: &[u8], format: Format, encoding: Encoding) -> Vec<u8> {
+		match encoding {
+			Encoding::Utf8 => encoding::map_utf8_runs(bytes, |text| self.process(text, format)),
+			encoding => encoding.encode(&self.process(&encoding.decode(bytes), format)),
+		}
+	}
+
+	/// Transforms an input and writes it to an output.
+	///
+	/// Plain text from standard input is processed line by line, unless it is in UTF-16. With
+	/// several jobs, lines are read in batches which are transformed in parallel.
+	///
+	/// # Errors
+	///
+	/// Returns an error if reading or writing fails.
+	pub fn copy<W: Write>(&self, input: &Input, output: &mut W) -> Result<(), CliError> {
+		let format = self.format_of(input);
+		let bytes = match input {
+			Input::Stdin => {
+				let stdin = io::stdin();
+				let mut stdin = stdin.lock();
+				let encoding = self.encoding_of(stdin.fill_buf().map_err(CliError::stdio)?);
+				if format == Format::Plain && encoding.is_ascii_compatible() {
+					let batch_length = if self.jobs > 1 { STDIN_BATCH_LENGTH } else { 0 };
+					let mut lines = Vec::new();
+					loop {
+						let read_size = stdin
+							.read_until(b'\n', &mut lines)
+							.map_err(CliError::stdio)?;
+						if !lines.is_empty() && (read_size == 0 || lines.len() >= batch_length) {
+							output
+								.write_all(&self.process_bytes(&lines, format, encoding))
+								.map_err(CliError::stdio)?;
+							lines.clear();
+						}
+						if read_size == 0 {
+							return Ok(());
+						}
+					}
+				}
+				let mut bytes = Vec::new();
+				stdin.read_to_end(&mut bytes).map_err(CliError::stdio)?;
+				bytes
+			}
+			Input::File(path) => fs::read(path).map_err(|error| CliError::io(path, error))?,
+		};
+		let encoding = self.encoding_of(&bytes);
+		output
+			.write_all(&self.process_bytes(&bytes, format, encoding))
+			.map_err(CliError::stdio)
+	}
+
+	/// Transforms a file, replacing its contents.
+	///
+	/// The new contents are written to a temporary file, which then replaces the original. If a
+	/// backup suffix is given, the original is kept under the suffixed name.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the input is standard input, or if reading or writing fails.
+	pub fn edit_in_place(
+		&self,
+		input: &Input,
+		backup_suffix: Option<&str>,
+	) -> Result<(), CliError> {
+		let Input::File(path) = input else {
+			return Err(CliError::InPlaceStdin);
+		};
+		let bytes = fs::read(path).map_err(|error| CliError::io(path, error))?;
+		let transformed =
+			self.process_bytes(&bytes, self.format_of(input), self.encoding_of(&bytes));
+
+		let temporary_path = suffixed_path(path, ".porcus-tmp");
+		fs::write(&temporary_path, transformed)
+			.map_err(|error| CliError::io(&temporary_path, error))?;
+		if let Ok(metadata) = fs::metadata(path) {
+			fs::set_permissions(&temporary_path, metadata.permissions())
+				.map_err(|error| CliError::io(&temporary_path, error))?;
+		}
+		if let Some(suffix) = backup_suffix {
+			let backup_path = suffixed_path(path, suffix);
+			fs::copy(path, &backup_path).map_err(|error| CliError::io(&backup_path, error))?;
+		}
+		fs::rename(&temporary_path, path).map_err(|error| CliError::io(path, error))
+	}
+}
+
+/// Opens the output for [`Destination::Stdout`] or [`Destination::File`].
+///
+/// # Errors
+///
+/// Returns an error if the output file is one of the inputs, which creating it would truncate
+/// before it is read, or if it cannot be created.
+pub fn open_output(
+	destination: &Destination,
+	inputs: &[Input],
+) -> Result<Box<dyn Write>, CliError> {
+	match destination {
+		Destination::File(path) => {
+			if let Ok(output) = fs::canonicalize(path) {
+				let is_output = |input: &Input| match input {
+					Input::File(input) => {
+						fs::canonicalize(input).is_ok_and(|input| input == output)
+					}
+					Input::Stdin => false,
+				};
+				if inputs.iter().any(is_output) {
+					return Err(CliError::OutputIsInput(path.clone()));
+				}
+			}
+			let file = File::create(path).map_err(|error| CliError::io(path, error))?;
+			Ok(Box::new(BufWriter::new(file)))
+		}
+		_ => Ok(Box::new(io::stdout())),
+	}
+}
+
+/// Lists the inputs to process, in order.
+///
+/// Files are listed as given. Directories are searched recursively, in name order, for files
+/// accepted by the filter. With no files or directories, standard input is read.
+///
+/// Directories which cannot be read are reported and skipped. Symbolic links to directories are
+/// not followed, so that a link cycle cannot make the search endless.
+pub fn collect_inputs(
+	files: &[&str],
+	directories: &[&str],
+	filter: &Filter,
+	report: &mut dyn FnMut(CliError),
+) -> Vec<Input> {
+	if files.is_empty() && directories.is_empty() {
+		return vec![Input::Stdin];
+	}
+
+	let mut inputs = files
+		.iter()
+		.map(|file| match *file {
+			"-" => Input::Stdin,
+			file => Input::File(PathBuf::from(file)),
+		})
+		.collect::<Vec<Input>>();
+	for directory in directories {
+		let root = Path::new(directory);
+		walk(root, root, filter, &mut inputs, report);
+	}
+	inputs
+}
+
+fn walk(
+	root: &Path,
+	directory: &Path,
+	filter: &Filter,
+	inputs: &mut Vec<Input>,
+	report: &mut dyn FnMut(CliError),
+) {
+	let entries = match fs::read_dir(directory) {
+		Ok(entries) => entries,
+		Err(error) => return report(CliError::io(directory, error)),
+	};
+	let mut paths = Vec::new();
+	for entry in entries {
+		match entry.and_then(|entry| Ok((entry.path(), entry.file_type()?))) {
+			Ok(path) => paths.push(path),
+			Err(error) => report(CliError::io(directory, error)),
+		}
+	}
+	paths.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+	for (path, file_type) in paths {
+		if file_type.is_dir() {
+			walk(root, &path, filter, inputs, report);
+		} else if file_type.is_symlink() && path.is_dir() {
+			continue;
+		} else if filter.accepts(path.strip_prefix(root).unwrap_or(&path)) {
+			inputs.push(Input::File(path));
+		}
+	}
+}
+
+/// Splits text after the first line break following each multiple of the chunk length.
+fn split_lines(text: &str, chunk_length: usize) -> Vec<&str> {
+	let mut chunks = Vec::new();
+	let mut start = 0;
+	while text.len() - start > chunk_length {
+		let Some(line_end) = text.as_bytes()[start + chunk_length..]
+			.iter()
+			.position(|&byte| byte == b'\n')
+		else {
+			break;
+		};
+		let end = start + chunk_length + line_end + 1;
+		chunks.push(&text[start..end]);
+		start = end;
+	}
+	if start < text.len() {
+		chunks.push(&text[start..]);
+	}
+	chunks
+}
+
+fn suffixed_path(path: &Path, suffix: &str) -> PathBuf {
+	let mut name = path.as_os_str().to_owned();
+	name.push(suffix);
+	PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod test_filter {
+	use super::*;
+
+	#[test]
+	fn include_all_by_default() {
+		let filter = Filter::new(&[], &[]).unwrap();
+		assert!(filter.accepts(Path::new("a/b.txt")));
+	}
+
+	#[test]
+	fn include_and_exclude() {
+		let filter = Filter::new(&["*.md", "docs/**"], &["CHANGELOG.md"]).unwrap();
+		assert!(filter.accepts(Path::new("README.md")));
+		assert!(filter.accepts(Path::new("guide/intro.md")));
+		assert!(filter.accepts(Path::new("docs/index.html")));
+		assert!(!filter.accepts(Path::new("src/main.rs")));
+		assert!(!filter.accepts(Path::new("CHANGELOG.md")));
+		assert!(!filter.accepts(Path::new("old/CHANGELOG.md")));
+	}
+
+	#[test]
+	fn invalid_pattern() {
+		assert!(Filter::new(&["a[b"], &[]).is_err());
+	}
+}
+
+#[cfg(test)]
+mod test_jobs {
+	use super::*;
+
+	#[test]
+	fn split_lines_keeps_text() {
+		let text = "one\ntwo\n\nthree\r\nfour";
+		assert_eq!(
+			split_lines(text, 2),
+			vec!["one\n", "two\n", "\nthree\r\n", "four"]
+		);
+		assert_eq!(split_lines(text, 8), vec!["one\ntwo\n\n", "three\r\nfour"]);
+		assert_eq!(split_lines(text, 100), vec![text]);
+		assert_eq!(split_lines("", 2), Vec::<&str>::new());
+		assert_eq!(split_lines("xé\né\n", 2), vec!["xé\n", "é\n"]);
+	}
+
+	#[test]
+	fn parallel_output_matches() {
+		let transform = |text: &str| text.to_uppercase();
+		let text = "Pig latin\n<b>Bold</b> and `code`\n".repeat(10_000);
+		for format in [Format::Plain, Format::Markdown, Format::Html] {
+			let processor = |jobs| Processor {
+				transform: &transform,
+				format: Some(format),
+				encoding: None,
+				jobs,
+			};
+			assert_eq!(
+				processor(4).process(&text, format),
+				processor(1).process(&text, format)
+			);
+		}
+	}
+
+	#[test]
+	fn parallel_chunks_split_on_characters() {
+		let transform = |text: &str| text.to_uppercase();
+		// Lines of 10 bytes, so that the first chunk boundary falls inside an `é`.
+		let text = "xéééé\n".repeat(16_400);
+		let processor = |jobs| Processor {
+			transform: &transform,
+			format: Some(Format::Plain),
+			encoding: None,
+			jobs,
+		};
+		assert_eq!(
+			processor(4).process(&text, Format::Plain),
+			text.to_uppercase()
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_paths {
+	use super::*;
+
+	fn temporary_directory(name: &str) -> PathBuf {
+		let directory =
+			std::env::temp_dir().join(format!("porcus-{}-{}", name, std::process::id()));
+		let _ = fs::remove_dir_all(&directory);
+		fs::create_dir_all(&directory).unwrap();
+		directory
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn walk_skips_directory_links() {
+		let root = temporary_directory("walk");
+		fs::create_dir(root.join("a")).unwrap();
+		fs::write(root.join("a/pig.txt"), "pig").unwrap();
+		std::os::unix::fs::symlink(&root, root.join("a/loop")).unwrap();
+		std::os::unix::fs::symlink(root.join("a/pig.txt"), root.join("link.txt")).unwrap();
+
+		let filter = Filter::new(&[], &[]).unwrap();
+		let inputs = collect_inputs(&[], &[root.to_str().unwrap()], &filter, &mut |error| {
+			panic!("{}", error)
+		});
+		assert_eq!(
+			inputs,
+			vec![
+				Input::File(root.join("a/pig.txt")),
+				Input::File(root.join("link.txt"))
+			]
+		);
+		fs::remove_dir_all(&root).unwrap();
+	}
+
+	#[test]
+	fn output_cannot_be_input() {
+		let root = temporary_directory("output");
+		let path = root.join("pig.txt");
+		fs::write(&path, "pig").unwrap();
+		let parent_path = root
+			.join("..")
+			.join(root.file_name().unwrap())
+			.join("pig.txt");
+		let inputs = [Input::Stdin, Input::File(parent_path)];
+
+		let destination = Destination::File(root.join(".").join("pig.txt"));
+		assert!(matches!(
+			open_output(&destination, &inputs),
+			Err(CliError::OutputIsInput(_))
+		));
+		assert_eq!(fs::read_to_string(&path).unwrap(), "pig");
+
+		let destination = Destination::File(root.join("latin.txt"));
+		assert!(open_output(&destination, &inputs).is_ok());
+		fs::remove_dir_all(&root).unwrap();
+	}
+}
@@ -0,0 +1,5 @@
+//! Helpers for the command-line interface.
+
+pub mod config;
+pub mod error;
+pub mod files;
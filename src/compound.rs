@@ -0,0 +1,281 @@
+//! Hyphenated compounds, elisions and contractions.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::compound::{Contraction, Elision, Hyphenation};
+//! use porcus::PigLatinTransformer;
+//!
+//! let transformer = PigLatinTransformer::default()
+//!     .with_hyphenation(Hyphenation::Whole)
+//!     .with_elision(Elision::Attach)
+//!     .with_contraction(Contraction::KeepClitic);
+//! assert_eq!(
+//!     transformer.to_pig_latin("L'eau, don't e-mail"),
+//!     "L'eauway, odayn't e-mailway"
+//! );
+//! ```
+
+use crate::latin::{CONTRACTION_CLITICS, ELISIONS, HYPHENS};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// How hyphenated compounds are translated.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Hyphenation {
+	/// Each part is translated, e.g. `T-shirt` becomes `Tay-irtshay`.
+	#[default]
+	PerPart,
+	/// The compound is translated as a single word, e.g. `mother-in-law` becomes
+	/// `other-in-lawmay`. Hyphens within the moved consonants are moved with them.
+	Whole,
+}
+
+impl fmt::Display for Hyphenation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::PerPart => "per-part",
+			Self::Whole => "whole",
+		})
+	}
+}
+
+impl Hyphenation {
+	/// Names of all strategies, as accepted by [`Hyphenation::from_name`].
+	pub const NAMES: &'static [&'static str] = &["per-part", "whole"];
+
+	/// Gets a strategy by name.
+	///
+	/// Returns `None` for unknown names.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"per-part" => Some(Self::PerPart),
+			"whole" => Some(Self::Whole),
+			_ => None,
+		}
+	}
+}
+
+/// How elided words such as French `l'` or Italian `dell'` are translated.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Elision {
+	/// The elided word is rotated with the next word, e.g. `L'eau` becomes `Eaul'ay`.
+	#[default]
+	Rotate,
+	/// The elided word is kept in front of the next word, e.g. `L'eau` becomes `L'eauway`.
+	Attach,
+	/// The elided word is translated on its own, e.g. `L'eau` becomes `Lay'eauway`.
+	Separate,
+}
+
+impl fmt::Display for Elision {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Rotate => "rotate",
+			Self::Attach => "attach",
+			Self::Separate => "separate",
+		})
+	}
+}
+
+impl Elision {
+	/// Names of all strategies, as accepted by [`Elision::from_name`].
+	pub const NAMES: &'static [&'static str] = &["rotate", "attach", "separate"];
+
+	/// Gets a strategy by name.
+	///
+	/// Returns `None` for unknown names.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"rotate" => Some(Self::Rotate),
+			"attach" => Some(Self::Attach),
+			"separate" => Some(Self::Separate),
+			_ => None,
+		}
+	}
+}
+
+/// How English contractions such as `don't` or `we'll` are translated.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Contraction {
+	/// The contraction is translated as a single word, e.g. `don't` becomes `on'tday`.
+	#[default]
+	Whole,
+	/// The stem is translated and the clitic kept after it, e.g. `don't` becomes `odayn't`.
+	KeepClitic,
+}
+
+impl fmt::Display for Contraction {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Whole => "whole",
+			Self::KeepClitic => "keep-clitic",
+		})
+	}
+}
+
+impl Contraction {
+	/// Names of all strategies, as accepted by [`Contraction::from_name`].
+	pub const NAMES: &'static [&'static str] = &["whole", "keep-clitic"];
+
+	/// Gets a strategy by name.
+	///
+	/// Returns `None` for unknown names.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"whole" => Some(Self::Whole),
+			"keep-clitic" => Some(Self::KeepClitic),
+			_ => None,
+		}
+	}
+}
+
+/// Joins words separated by single hyphens into compounds.
+///
+/// The words must be consecutive slices covering the string, as returned by
+/// [`split_word_bounds`](https://unicode-rs.github.io/unicode-segmentation/unicode_segmentation/trait.UnicodeSegmentation.html#tymethod.split_word_bounds).
+///
+/// ```
+/// # use porcus::compound::join_hyphenated;
+/// let s = "a mother-in-law - no";
+/// let words = vec!["a", " ", "mother", "-", "in", "-", "law", " ", "-", " ", "no"];
+/// assert_eq!(
+///     join_hyphenated(s, words),
+///     vec!["a", " ", "mother-in-law", " ", "-", " ", "no"]
+/// );
+/// ```
+#[must_use]
+pub fn join_hyphenated<'a>(s: &'a str, words: Vec<&'a str>) -> Vec<&'a str> {
+	let mut joined = Vec::with_capacity(words.len());
+	let mut index = 0;
+	let mut start = 0;
+	while index < words.len() {
+		let mut end = start + words[index].len();
+		if starts_with_letter(words[index]) {
+			while let [hyphen, part, ..] = words[index + 1..] {
+				if !is_hyphen(hyphen) || !starts_with_letter(part) {
+					break;
+				}
+				end += hyphen.len() + part.len();
+				index += 2;
+			}
+		}
+		joined.push(&s[start..end]);
+		start = end;
+		index += 1;
+	}
+	joined
+}
+
+/// Checks whether a grapheme is a hyphen.
+#[must_use]
+pub fn is_hyphen(grapheme: &str) -> bool {
+	let mut chars = grapheme.chars();
+	chars.next().is_some_and(|c| HYPHENS.contains(&c)) && chars.next().is_none()
+}
+
+/// Splits an elided word from the start of a word, including its apostrophe.
+///
+/// ```
+/// # use porcus::compound::split_elision;
+/// assert_eq!(split_elision("L'eau"), Some(("L'", "eau")));
+/// assert_eq!(split_elision("qu’il"), Some(("qu’", "il")));
+/// assert_eq!(split_elision("aujourd'hui"), None);
+/// assert_eq!(split_elision("don't"), None);
+/// ```
+#[must_use]
+pub fn split_elision(word: &str) -> Option<(&str, &str)> {
+	let apostrophe = word.find(is_apostrophe)?;
+	let elided = &word[..apostrophe];
+	let split = apostrophe + word[apostrophe..].chars().next()?.len_utf8();
+	let rest = &word[split..];
+	(ELISIONS.contains(elided.to_lowercase().as_str()) && starts_with_letter(rest))
+		.then(|| word.split_at(split))
+}
+
+/// Splits an English clitic from the end of a word.
+///
+/// ```
+/// # use porcus::compound::split_contraction;
+/// assert_eq!(split_contraction("don't"), Some(("do", "n't")));
+/// assert_eq!(split_contraction("WE’LL"), Some(("WE", "’LL")));
+/// assert_eq!(split_contraction("n't"), None);
+/// assert_eq!(split_contraction("cats"), None);
+/// ```
+#[must_use]
+pub fn split_contraction(word: &str) -> Option<(&str, &str)> {
+	CONTRACTION_CLITICS.iter().find_map(|clitic| {
+		let start = word.char_indices().rev().nth(clitic.chars().count() - 1)?.0;
+		let (stem, rest) = word.split_at(start);
+		(rest.replace(is_apostrophe, "'").to_lowercase() == *clitic && starts_with_letter(stem))
+			.then_some((stem, rest))
+	})
+}
+
+fn is_apostrophe(c: char) -> bool {
+	matches!(c, '\'' | '’')
+}
+
+fn starts_with_letter(word: &str) -> bool {
+	word.chars().next().is_some_and(char::is_alphabetic)
+}
+
+#[cfg(test)]
+mod test_join_hyphenated {
+	use super::*;
+	use unicode_segmentation::UnicodeSegmentation;
+
+	fn join(s: &str) -> Vec<&str> {
+		join_hyphenated(s, s.split_word_bounds().collect())
+	}
+
+	#[test]
+	fn compounds() {
+		assert_eq!(join("T-shirt"), vec!["T-shirt"]);
+		assert_eq!(
+			join("e‑mail, x-ray."),
+			vec!["e‑mail", ",", " ", "x-ray", "."]
+		);
+	}
+
+	#[test]
+	fn not_compounds() {
+		assert_eq!(join("a - b"), vec!["a", " ", "-", " ", "b"]);
+		assert_eq!(join("a--b"), vec!["a", "-", "-", "b"]);
+		assert_eq!(join("-a-"), vec!["-", "a", "-"]);
+		assert_eq!(join("4-5"), vec!["4", "-", "5"]);
+	}
+}
+
+#[cfg(test)]
+mod test_split {
+	use super::*;
+
+	#[test]
+	fn elisions() {
+		assert_eq!(split_elision("d'Artagnan"), Some(("d'", "Artagnan")));
+		assert_eq!(split_elision("JUSQU'ICI"), Some(("JUSQU'", "ICI")));
+		assert_eq!(split_elision("dell'anno"), Some(("dell'", "anno")));
+		assert_eq!(split_elision("l'"), None);
+		assert_eq!(split_elision("O'Brien"), None);
+	}
+
+	#[test]
+	fn contractions() {
+		assert_eq!(split_contraction("can't"), Some(("ca", "n't")));
+		assert_eq!(split_contraction("I'm"), Some(("I", "'m")));
+		assert_eq!(split_contraction("they're"), Some(("they", "'re")));
+		assert_eq!(split_contraction("John's"), Some(("John", "'s")));
+		assert_eq!(split_contraction("o'clock"), None);
+		assert_eq!(split_contraction("'s"), None);
+	}
+}
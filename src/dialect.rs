@@ -0,0 +1,280 @@
+//! Rule variants of pig latin.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::dialect::Dialect;
+//! use porcus::PigLatinTransformer;
+//!
+//! let dialect = Dialect::from_preset("first-consonant").unwrap();
+//! let transformer = PigLatinTransformer::default().with_dialect(dialect);
+//! assert_eq!(transformer.to_pig_latin("string"), "tringsay");
+//! ```
+
+use alloc::string::String;
+use core::fmt;
+
+/// How many leading consonants are moved to the end of a word.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Onset {
+	/// All initial consonants are moved, e.g. `string` becomes `ingstray`.
+	#[default]
+	Cluster,
+	/// Only the first consonant is moved, e.g. `string` becomes `tringsay`.
+	Single,
+}
+
+/// How `y` and its variants are classified.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum YRule {
+	/// `y` is a consonant when followed by a vowel, e.g. `yak` becomes `akyay` but `Yvonne`
+	/// becomes `Yvonneway`.
+	#[default]
+	Contextual,
+	/// `y` is a consonant only at the start of a word, e.g. `Yvonne` becomes `Onneyvay` and
+	/// `rhythm` becomes `ythmrhay`.
+	Initial,
+	/// `y` is always a vowel, e.g. `yak` becomes `yakway`.
+	Vowel,
+}
+
+/// Set of rules deciding how a word is rotated.
+///
+/// # Examples
+///
+/// The default dialect moves all initial consonants, and treats `y` as a consonant only before a
+/// vowel.
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// let transformer = PigLatinTransformer::default();
+/// assert_eq!(transformer.to_pig_latin("string queen"), "ingstray ueenqay");
+/// ```
+///
+/// Dialects can be selected by name.
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::dialect::Dialect;
+/// let dialect = Dialect::from_preset("qu-cluster").unwrap();
+/// let transformer = PigLatinTransformer::default().with_dialect(dialect);
+/// assert_eq!(transformer.to_pig_latin("string queen"), "ingstray eenquay");
+/// ```
+///
+/// Or built rule by rule.
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::dialect::{Dialect, Onset, YRule};
+/// let dialect = Dialect::default()
+///     .with_onset(Onset::Single)
+///     .with_y_rule(YRule::Initial)
+///     .with_single_letter_suffix("yay");
+/// let transformer = PigLatinTransformer::default().with_dialect(dialect);
+/// assert_eq!(transformer.to_pig_latin("I spy Yvonne"), "Iyay pysay Vonneyay");
+/// ```
+///
+/// With the `serde` feature, dialects are serialized as a preset name if they match one, or as
+/// their rules otherwise.
+#[derive(Debug, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(try_from = "repr::DialectRepr", into = "repr::DialectRepr")
+)]
+pub struct Dialect {
+	onset: Onset,
+	qu_as_unit: bool,
+	y_rule: YRule,
+	single_letter_suffix: Option<String>,
+}
+
+impl fmt::Display for Dialect {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = Self::PRESETS
+			.iter()
+			.find(|name| Self::from_preset(name).as_ref() == Some(self));
+		match name {
+			Some(name) => f.write_str(name),
+			None => f.write_str("custom"),
+		}
+	}
+}
+
+impl Dialect {
+	/// Names of the built-in dialects, as accepted by [`Dialect::from_preset`].
+	pub const PRESETS: &'static [&'static str] = &[
+		"standard",
+		"first-consonant",
+		"qu-cluster",
+		"initial-y",
+		"playground",
+	];
+
+	/// Gets a built-in dialect by name.
+	///
+	/// - `standard`: the default rules.
+	/// - `first-consonant`: only the first consonant is moved, e.g. `string` → `tringsay`.
+	/// - `qu-cluster`: `qu` is moved as a unit, e.g. `queen` → `eenquay`.
+	/// - `initial-y`: `y` is a consonant only at the start of a word, e.g. `rhythm` → `ythmrhay`.
+	/// - `playground`: `qu` is moved as a unit, `y` is a consonant only at the start of a word,
+	///   and single-letter words get the suffix `yay`, e.g. `a` → `ayay`.
+	///
+	/// Returns `None` for unknown names.
+	///
+	/// ```
+	/// # use porcus::dialect::Dialect;
+	/// assert_eq!(Dialect::from_preset("standard"), Some(Dialect::default()));
+	/// assert_eq!(Dialect::from_preset("klingon"), None);
+	/// ```
+	#[must_use]
+	pub fn from_preset(name: &str) -> Option<Self> {
+		let dialect = Self::default();
+		match name {
+			"standard" => Some(dialect),
+			"first-consonant" => Some(dialect.with_onset(Onset::Single)),
+			"qu-cluster" => Some(dialect.with_qu_as_unit(true)),
+			"initial-y" => Some(dialect.with_y_rule(YRule::Initial)),
+			"playground" => Some(
+				dialect
+					.with_qu_as_unit(true)
+					.with_y_rule(YRule::Initial)
+					.with_single_letter_suffix("yay"),
+			),
+			_ => None,
+		}
+	}
+
+	/// Sets how many leading consonants are moved.
+	#[must_use]
+	pub const fn with_onset(mut self, onset: Onset) -> Self {
+		self.onset = onset;
+		self
+	}
+	/// Sets whether `qu` is moved as a single consonant.
+	#[must_use]
+	pub const fn with_qu_as_unit(mut self, qu_as_unit: bool) -> Self {
+		self.qu_as_unit = qu_as_unit;
+		self
+	}
+	/// Sets how `y` and its variants are classified.
+	#[must_use]
+	pub const fn with_y_rule(mut self, y_rule: YRule) -> Self {
+		self.y_rule = y_rule;
+		self
+	}
+	/// Sets a suffix appended to single-letter words instead of rotating them.
+	#[must_use]
+	pub fn with_single_letter_suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+		self.single_letter_suffix = Some(suffix.into());
+		self
+	}
+
+	/// Gets how many leading consonants are moved.
+	#[must_use]
+	pub const fn onset(&self) -> Onset {
+		self.onset
+	}
+	/// Gets whether `qu` is moved as a single consonant.
+	#[must_use]
+	pub const fn qu_as_unit(&self) -> bool {
+		self.qu_as_unit
+	}
+	/// Gets how `y` and its variants are classified.
+	#[must_use]
+	pub const fn y_rule(&self) -> YRule {
+		self.y_rule
+	}
+	/// Gets the suffix appended to single-letter words, if any.
+	#[must_use]
+	pub const fn single_letter_suffix(&self) -> Option<&String> {
+		self.single_letter_suffix.as_ref()
+	}
+}
+
+#[cfg(feature = "serde")]
+mod repr {
+	use super::{Dialect, Onset, YRule};
+	use alloc::format;
+	use alloc::string::{String, ToString};
+	use serde::{Deserialize, Serialize};
+
+	/// Serialized form of a dialect.
+	#[derive(Serialize, Deserialize)]
+	#[serde(untagged)]
+	pub enum DialectRepr {
+		Preset(String),
+		Rules(Rules),
+	}
+
+	#[derive(Default, Serialize, Deserialize)]
+	#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+	pub struct Rules {
+		onset: Onset,
+		qu_as_unit: bool,
+		y_rule: YRule,
+		#[serde(skip_serializing_if = "Option::is_none")]
+		single_letter_suffix: Option<String>,
+	}
+
+	impl From<Dialect> for DialectRepr {
+		fn from(dialect: Dialect) -> Self {
+			match dialect.to_string().as_str() {
+				"custom" => Self::Rules(Rules {
+					onset: dialect.onset,
+					qu_as_unit: dialect.qu_as_unit,
+					y_rule: dialect.y_rule,
+					single_letter_suffix: dialect.single_letter_suffix,
+				}),
+				name => Self::Preset(name.to_string()),
+			}
+		}
+	}
+
+	impl TryFrom<DialectRepr> for Dialect {
+		type Error = String;
+
+		fn try_from(repr: DialectRepr) -> Result<Self, Self::Error> {
+			match repr {
+				DialectRepr::Preset(name) => Self::from_preset(&name).ok_or_else(|| {
+					format!(
+						"unknown dialect `{}`, expected one of {}",
+						name,
+						Self::PRESETS.join(", ")
+					)
+				}),
+				DialectRepr::Rules(rules) => Ok(Self {
+					onset: rules.onset,
+					qu_as_unit: rules.qu_as_unit,
+					y_rule: rules.y_rule,
+					single_letter_suffix: rules.single_letter_suffix,
+				}),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod test_presets {
+	use super::*;
+
+	#[test]
+	fn all_presets_exist() {
+		for name in Dialect::PRESETS {
+			assert!(Dialect::from_preset(name).is_some(), "{}", name);
+		}
+	}
+
+	#[test]
+	fn display_preset_name() {
+		for name in Dialect::PRESETS {
+			assert_eq!(Dialect::from_preset(name).unwrap().to_string(), *name);
+		}
+		let custom = Dialect::default().with_single_letter_suffix("hay");
+		assert_eq!(custom.to_string(), "custom");
+	}
+}
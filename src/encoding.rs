@@ -0,0 +1,285 @@
+//! Byte-level input and legacy character encodings.
+//!
+//! # Usage
+//!
+//! Invalid UTF-8 is passed through unchanged, and the valid text around it is transformed.
+//!
+//! ```
+//! use porcus::PigLatinTransformer;
+//!
+//! let transformer = PigLatinTransformer::default();
+//! let bytes = transformer.to_pig_latin_bytes(b"Hello \xff world");
+//! assert_eq!(bytes, b"Ellohay \xff orldway");
+//! ```
+//!
+//! Legacy encodings are decoded, transformed, then encoded back.
+//!
+//! ```
+//! use porcus::encoding::Encoding;
+//! use porcus::PigLatinTransformer;
+//!
+//! let transformer = PigLatinTransformer::default();
+//! let cp1252 = b"\x93Caf\xe9\x94";
+//! let text = Encoding::Windows1252.decode(cp1252);
+//! assert_eq!(text, "“Café”");
+//! let pig_latin = Encoding::Windows1252.encode(&transformer.to_pig_latin(text));
+//! assert_eq!(pig_latin, b"\x93Af\xe9cay\x94");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Characters of Windows-1252 bytes `0x80` to `0x9F`.
+///
+/// Undefined bytes are mapped to the C1 control character with the same value, so that they
+/// round-trip.
+const WINDOWS_1252_HIGH: [char; 32] = [
+	'€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8d}', 'Ž', '\u{8f}',
+	'\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9d}', 'ž', 'Ÿ',
+];
+
+/// Replacement for characters which cannot be encoded.
+const UNENCODABLE: u8 = b'?';
+
+/// Character encoding of a byte stream.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Encoding {
+	/// UTF-8. Invalid sequences are passed through unchanged.
+	#[default]
+	#[cfg_attr(feature = "serde", serde(rename = "utf-8"))]
+	Utf8,
+	/// ISO-8859-1.
+	#[cfg_attr(feature = "serde", serde(rename = "latin1"))]
+	Latin1,
+	/// Windows-1252, a superset of the printable characters of ISO-8859-1.
+	#[cfg_attr(feature = "serde", serde(rename = "windows-1252"))]
+	Windows1252,
+	/// UTF-16, little-endian.
+	#[cfg_attr(feature = "serde", serde(rename = "utf-16le"))]
+	Utf16Le,
+	/// UTF-16, big-endian.
+	#[cfg_attr(feature = "serde", serde(rename = "utf-16be"))]
+	Utf16Be,
+}
+
+impl fmt::Display for Encoding {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Utf8 => "utf-8",
+			Self::Latin1 => "latin1",
+			Self::Windows1252 => "windows-1252",
+			Self::Utf16Le => "utf-16le",
+			Self::Utf16Be => "utf-16be",
+		})
+	}
+}
+
+impl Encoding {
+	/// Names of all encodings, as accepted by [`Encoding::from_name`].
+	pub const NAMES: &'static [&'static str] =
+		&["utf-8", "latin1", "windows-1252", "utf-16le", "utf-16be"];
+
+	/// Gets an encoding by name.
+	///
+	/// Common aliases such as `cp1252` and `iso-8859-1` are accepted, case-insensitively.
+	///
+	/// ```
+	/// # use porcus::encoding::Encoding;
+	/// assert_eq!(Encoding::from_name("CP1252"), Some(Encoding::Windows1252));
+	/// assert_eq!(Encoding::from_name("ebcdic"), None);
+	/// ```
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name.to_lowercase().as_str() {
+			"utf-8" | "utf8" => Some(Self::Utf8),
+			"latin1" | "latin-1" | "iso-8859-1" | "iso8859-1" => Some(Self::Latin1),
+			"windows-1252" | "cp1252" => Some(Self::Windows1252),
+			"utf-16le" | "utf16le" => Some(Self::Utf16Le),
+			"utf-16be" | "utf16be" => Some(Self::Utf16Be),
+			_ => None,
+		}
+	}
+
+	/// Detects the encoding of a byte stream from its byte order mark.
+	///
+	/// Streams without a UTF-16 byte order mark are assumed to be UTF-8.
+	///
+	/// ```
+	/// # use porcus::encoding::Encoding;
+	/// assert_eq!(Encoding::detect(b"\xff\xfeH\x00i\x00"), Encoding::Utf16Le);
+	/// assert_eq!(Encoding::detect(b"\xfe\xff\x00H\x00i"), Encoding::Utf16Be);
+	/// assert_eq!(Encoding::detect(b"Caf\xe9"), Encoding::Utf8);
+	/// ```
+	#[must_use]
+	pub fn detect(bytes: &[u8]) -> Self {
+		match bytes {
+			[0xff, 0xfe, ..] => Self::Utf16Le,
+			[0xfe, 0xff, ..] => Self::Utf16Be,
+			_ => Self::Utf8,
+		}
+	}
+
+	/// Checks whether ASCII characters, including line breaks, are encoded as single bytes.
+	#[must_use]
+	pub const fn is_ascii_compatible(self) -> bool {
+		!matches!(self, Self::Utf16Le | Self::Utf16Be)
+	}
+
+	/// Decodes bytes to text.
+	///
+	/// Invalid UTF-8 and unpaired UTF-16 surrogates are replaced with `U+FFFD`. Use
+	/// [`map_utf8_runs`] to keep invalid UTF-8 instead. A UTF-16 byte order mark is kept as
+	/// `U+FEFF`.
+	#[must_use]
+	pub fn decode(self, bytes: &[u8]) -> String {
+		match self {
+			Self::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+			Self::Latin1 => bytes.iter().map(|&byte| char::from(byte)).collect(),
+			Self::Windows1252 => bytes
+				.iter()
+				.map(|&byte| match byte {
+					0x80..=0x9f => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+					_ => char::from(byte),
+				})
+				.collect(),
+			Self::Utf16Le | Self::Utf16Be => {
+				let units = bytes.chunks(2).map(|pair| match (self, pair) {
+					(Self::Utf16Le, &[low, high]) => u16::from_le_bytes([low, high]),
+					(_, &[high, low]) => u16::from_be_bytes([high, low]),
+					_ => 0xfffd,
+				});
+				char::decode_utf16(units)
+					.map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+					.collect()
+			}
+		}
+	}
+
+	/// Encodes text to bytes.
+	///
+	/// Characters which cannot be encoded in Latin-1 or Windows-1252 are replaced with `?`.
+	///
+	/// ```
+	/// # use porcus::encoding::Encoding;
+	/// assert_eq!(Encoding::Latin1.encode("Ça €"), b"\xc7a ?");
+	/// assert_eq!(Encoding::Windows1252.encode("Ça €"), b"\xc7a \x80");
+	/// ```
+	#[must_use]
+	pub fn encode(self, s: &str) -> Vec<u8> {
+		match self {
+			Self::Utf8 => s.as_bytes().to_vec(),
+			Self::Latin1 => s
+				.chars()
+				.map(|c| u8::try_from(c).unwrap_or(UNENCODABLE))
+				.collect(),
+			Self::Windows1252 => s.chars().map(encode_windows_1252).collect(),
+			Self::Utf16Le => s.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+			Self::Utf16Be => s.encode_utf16().flat_map(u16::to_be_bytes).collect(),
+		}
+	}
+}
+
+fn encode_windows_1252(c: char) -> u8 {
+	match WINDOWS_1252_HIGH.iter().position(|&high| high == c) {
+		Some(index) => 0x80 + u8::try_from(index).unwrap_or_default(),
+		None => match u8::try_from(c) {
+			Ok(byte) if !(0x80..=0x9f).contains(&byte) => byte,
+			_ => UNENCODABLE,
+		},
+	}
+}
+
+/// Applies a function to each run of valid UTF-8, passing invalid bytes through unchanged.
+///
+/// ```
+/// # use porcus::encoding::map_utf8_runs;
+/// let bytes = map_utf8_runs(b"abc\xc3def", str::to_uppercase);
+/// assert_eq!(bytes, b"ABC\xc3DEF");
+/// ```
+pub fn map_utf8_runs<F: FnMut(&str) -> String>(bytes: &[u8], mut f: F) -> Vec<u8> {
+	let mut output = Vec::with_capacity(bytes.len());
+	for chunk in bytes.utf8_chunks() {
+		if !chunk.valid().is_empty() {
+			output.extend_from_slice(f(chunk.valid()).as_bytes());
+		}
+		output.extend_from_slice(chunk.invalid());
+	}
+	output
+}
+
+#[cfg(test)]
+mod test_round_trip {
+	use super::*;
+
+	#[test]
+	fn single_byte_encodings() {
+		let bytes = (0..=255).collect::<Vec<u8>>();
+		for encoding in [Encoding::Latin1, Encoding::Windows1252] {
+			assert_eq!(encoding.encode(&encoding.decode(&bytes)), bytes);
+		}
+	}
+
+	#[test]
+	fn utf16() {
+		let text = "\u{feff}Ça 🦀";
+		for encoding in [Encoding::Utf16Le, Encoding::Utf16Be] {
+			let bytes = encoding.encode(text);
+			assert_eq!(Encoding::detect(&bytes), encoding);
+			assert_eq!(encoding.decode(&bytes), text);
+		}
+	}
+
+	#[test]
+	fn names() {
+		for name in Encoding::NAMES {
+			assert_eq!(Encoding::from_name(name).unwrap().to_string(), *name);
+		}
+	}
+}
+
+#[cfg(test)]
+mod test_decode {
+	use super::*;
+
+	#[test]
+	fn invalid_utf16() {
+		assert_eq!(Encoding::Utf16Le.decode(b"\x00\xd8a\x00"), "\u{fffd}a");
+		assert_eq!(Encoding::Utf16Be.decode(b"\x00a\x00"), "a\u{fffd}");
+	}
+
+	#[test]
+	fn windows_1252_undefined_bytes() {
+		assert_eq!(Encoding::Windows1252.decode(b"\x81\x9d"), "\u{81}\u{9d}");
+	}
+}
+
+#[cfg(test)]
+mod test_map_utf8_runs {
+	use super::*;
+
+	#[test]
+	fn passes_invalid_bytes() {
+		let inputs: [&[u8]; 5] = [
+			b"",
+			b"\xff",
+			b"\xe2\x82",
+			b"a\xe2\x82b",
+			b"\xc0\xafok\xed\xa0\x80",
+		];
+		for input in inputs {
+			assert_eq!(map_utf8_runs(input, str::to_string), input);
+		}
+	}
+
+	#[test]
+	fn transforms_valid_runs() {
+		let mut runs = Vec::new();
+		map_utf8_runs(b"one\xfftwo\xfe\xfdthree", |run| {
+			runs.push(run.to_string());
+			String::new()
+		});
+		assert_eq!(runs, vec!["one", "two", "three"]);
+	}
+}
@@ -0,0 +1,279 @@
+//! Words with a fixed translation.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::exceptions::Exceptions;
+//! use porcus::PigLatinTransformer;
+//!
+//! let exceptions = Exceptions::new()
+//!     .with_kept("NASA")
+//!     .with_replacement("GitHub", "ItHubgay");
+//! let transformer = PigLatinTransformer::default().with_exceptions(exceptions);
+//! assert_eq!(
+//!     transformer.to_pig_latin("NASA on github"),
+//!     "NASA onway ithubgay"
+//! );
+//! ```
+
+use crate::case::Locale;
+use crate::normalization::lookup_key;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+
+/// Fixed translation of a word.
+#[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Exception {
+	/// The word is left unchanged.
+	Keep,
+	/// The word is replaced, matching its case.
+	Replace(String),
+}
+
+/// List of words with a fixed translation.
+///
+/// Words are matched case-insensitively. Replacements are converted to the case of the matched
+/// word, unless it is mixed-case.
+///
+/// ```
+/// # use porcus::exceptions::Exceptions;
+/// let exceptions = Exceptions::new().with_replacement("iPhone", "iPhoneway");
+/// assert_eq!(exceptions.apply("iPhone").as_deref(), Some("iPhoneway"));
+/// assert_eq!(exceptions.apply("IPHONE").as_deref(), Some("IPHONEWAY"));
+/// assert_eq!(exceptions.apply("iphone").as_deref(), Some("iphoneway"));
+/// assert_eq!(exceptions.apply("iPad"), None);
+/// ```
+///
+/// With the `serde` feature, exceptions are serialized as a list of kept words and a table of
+/// replacements.
+#[derive(Debug, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(from = "repr::ExceptionsRepr", into = "repr::ExceptionsRepr")
+)]
+pub struct Exceptions {
+	entries: BTreeMap<String, Exception>,
+}
+
+impl Exceptions {
+	/// Creates an empty list.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			entries: BTreeMap::new(),
+		}
+	}
+
+	/// Reads exceptions, one per line.
+	///
+	/// Each line holds either a word to keep, or a word, a tab, and its replacement. Empty lines
+	/// and lines starting with `#` are ignored.
+	///
+	/// ```
+	/// # use porcus::exceptions::{Exception, Exceptions};
+	/// let tsv = "# brands\nNASA\nGitHub\tItHubgay\n";
+	/// let exceptions = Exceptions::from_tsv(tsv.as_bytes()).unwrap();
+	/// assert_eq!(exceptions.get("nasa"), Some(&Exception::Keep));
+	/// assert_eq!(
+	///     exceptions.get("github"),
+	///     Some(&Exception::Replace(String::from("ItHubgay")))
+	/// );
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if reading fails or the input is not valid UTF-8.
+	#[cfg(feature = "std")]
+	pub fn from_tsv<R: BufRead>(reader: R) -> io::Result<Self> {
+		let mut exceptions = Self::new();
+		for line in reader.lines() {
+			let line = line?;
+			if line.starts_with('#') {
+				continue;
+			}
+			let mut fields = line.splitn(2, '\t');
+			let word = fields.next().unwrap_or_default().trim();
+			let exception = match fields.next().map(str::trim) {
+				Some(replacement) if !replacement.is_empty() => {
+					Exception::Replace(replacement.to_string())
+				}
+				_ => Exception::Keep,
+			};
+			exceptions.insert(word, exception);
+		}
+		Ok(exceptions)
+	}
+
+	/// Adds a word to leave unchanged.
+	#[must_use]
+	pub fn with_kept<S: AsRef<str>>(mut self, word: S) -> Self {
+		self.insert(word, Exception::Keep);
+		self
+	}
+	/// Adds a word to replace.
+	#[must_use]
+	pub fn with_replacement<S: AsRef<str>, R: Into<String>>(
+		mut self,
+		word: S,
+		replacement: R,
+	) -> Self {
+		self.insert(word, Exception::Replace(replacement.into()));
+		self
+	}
+
+	/// Adds a word, replacing any previous exception for it.
+	pub fn insert<S: AsRef<str>>(&mut self, word: S, exception: Exception) {
+		let word = lookup_key(word.as_ref());
+		if !word.is_empty() {
+			self.entries.insert(word, exception);
+		}
+	}
+
+	/// Adds all words from another list, replacing previous exceptions for them.
+	pub fn extend(&mut self, other: Self) {
+		self.entries.extend(other.entries);
+	}
+
+	/// Gets the exception for a word.
+	#[must_use]
+	pub fn get(&self, word: &str) -> Option<&Exception> {
+		self.entries.get(&lookup_key(word))
+	}
+
+	/// Gets the translation of a word, if it is an exception.
+	#[must_use]
+	pub fn apply(&self, word: &str) -> Option<String> {
+		self.apply_with_locale(word, Locale::Root)
+	}
+
+	/// Gets the translation of a word, if it is an exception, matching it and its case with the
+	/// rules of a locale.
+	///
+	/// ```
+	/// # use porcus::case::Locale;
+	/// # use porcus::exceptions::Exceptions;
+	/// let exceptions = Exceptions::new().with_replacement("izmir", "smirnay");
+	/// assert_eq!(
+	///     exceptions.apply_with_locale("İZMİR", Locale::Turkish).as_deref(),
+	///     Some("SMİRNAY")
+	/// );
+	/// assert_eq!(exceptions.apply("İZMİR"), None);
+	/// ```
+	#[must_use]
+	pub fn apply_with_locale(&self, word: &str, locale: Locale) -> Option<String> {
+		let exception = self.entries.get(&lookup_key(&locale.to_lowercase(word)));
+		exception.map(|exception| match exception {
+			Exception::Keep => word.to_string(),
+			Exception::Replace(replacement) => {
+				locale.to_case(replacement.as_str(), locale.detect_case(word))
+			}
+		})
+	}
+
+	/// Gets the number of words listed.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Checks whether no words are listed.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+}
+
+#[cfg(feature = "serde")]
+mod repr {
+	use super::{Exception, Exceptions};
+	use alloc::collections::BTreeMap;
+	use alloc::string::String;
+	use alloc::vec::Vec;
+	use serde::{Deserialize, Serialize};
+
+	/// Serialized form of exceptions.
+	#[derive(Default, Serialize, Deserialize)]
+	#[serde(default, deny_unknown_fields)]
+	pub struct ExceptionsRepr {
+		keep: Vec<String>,
+		replace: BTreeMap<String, String>,
+	}
+
+	impl From<Exceptions> for ExceptionsRepr {
+		fn from(exceptions: Exceptions) -> Self {
+			let mut repr = Self::default();
+			for (word, exception) in exceptions.entries {
+				match exception {
+					Exception::Keep => repr.keep.push(word),
+					Exception::Replace(replacement) => {
+						repr.replace.insert(word, replacement);
+					}
+				}
+			}
+			repr
+		}
+	}
+
+	impl From<ExceptionsRepr> for Exceptions {
+		fn from(repr: ExceptionsRepr) -> Self {
+			let mut exceptions = Self::new();
+			for word in repr.keep {
+				exceptions.insert(word, Exception::Keep);
+			}
+			for (word, replacement) in repr.replace {
+				exceptions.insert(word, Exception::Replace(replacement));
+			}
+			exceptions
+		}
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_from_tsv {
+	use super::*;
+
+	#[test]
+	fn later_lines_win() {
+		let tsv = "NASA\tAsanay\n\n  nasa  \n";
+		let exceptions = Exceptions::from_tsv(tsv.as_bytes()).unwrap();
+		assert_eq!(exceptions.len(), 1);
+		assert_eq!(exceptions.get("NaSa"), Some(&Exception::Keep));
+	}
+
+	#[test]
+	fn empty_replacement_keeps() {
+		let exceptions = Exceptions::from_tsv("iPhone\t \n".as_bytes()).unwrap();
+		assert_eq!(exceptions.get("iphone"), Some(&Exception::Keep));
+	}
+
+	#[test]
+	fn rejects_invalid_utf8() {
+		assert!(Exceptions::from_tsv(&b"caf\xe9\n"[..]).is_err());
+	}
+}
+
+#[cfg(test)]
+mod test_apply {
+	use super::*;
+
+	#[test]
+	fn keep_matches_any_case() {
+		let exceptions = Exceptions::new().with_kept("GitHub");
+		for word in ["GitHub", "github", "GITHUB", "gItHuB"] {
+			assert_eq!(exceptions.apply(word).as_deref(), Some(word));
+		}
+	}
+
+	#[test]
+	fn replacement_matches_case() {
+		let exceptions = Exceptions::new().with_replacement("Élan", "élanway");
+		assert_eq!(exceptions.apply("e\u{301}lan").as_deref(), Some("élanway"));
+		assert_eq!(exceptions.apply("élan").as_deref(), Some("élanway"));
+		assert_eq!(exceptions.apply("Élan").as_deref(), Some("Élanway"));
+		assert_eq!(exceptions.apply("ÉLAN").as_deref(), Some("ÉLANWAY"));
+		assert_eq!(exceptions.apply("éLAN").as_deref(), Some("élanway"));
+	}
+}
@@ -0,0 +1,411 @@
+//! Markup-aware transformation of documents.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::format::Format;
+//! use porcus::PigLatinTransformer;
+//!
+//! let transformer = PigLatinTransformer::default();
+//! let html = "<p class=\"intro\">Hello &amp; welcome</p>";
+//! let pig_latin = Format::Html.map_prose(html, |prose| transformer.to_pig_latin(prose));
+//! assert_eq!(pig_latin, "<p class=\"intro\">Ellohay &amp; elcomeway</p>");
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// HTML elements whose content is not prose.
+const HTML_RAW_ELEMENTS: &[&str] = &["script", "style", "code", "pre"];
+
+/// Markup language of a document.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Format {
+	/// Plain text, transformed entirely.
+	#[default]
+	Plain,
+	/// Markdown. Code, URLs and HTML tags are left unchanged.
+	Markdown,
+	/// HTML or XML. Tags, comments, entities, and the content of `script`, `style`, `code` and
+	/// `pre` elements are left unchanged.
+	Html,
+}
+
+impl fmt::Display for Format {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Plain => "plain",
+			Self::Markdown => "markdown",
+			Self::Html => "html",
+		})
+	}
+}
+
+impl Format {
+	/// Names of all formats, as accepted by [`Format::from_name`].
+	pub const NAMES: &'static [&'static str] = &["plain", "markdown", "html"];
+
+	/// Gets a format by name.
+	///
+	/// Returns `None` for unknown names.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"plain" => Some(Self::Plain),
+			"markdown" => Some(Self::Markdown),
+			"html" => Some(Self::Html),
+			_ => None,
+		}
+	}
+
+	/// Guesses the format of a file from its extension.
+	///
+	/// Unknown extensions are treated as plain text.
+	///
+	/// ```
+	/// # use porcus::format::Format;
+	/// assert_eq!(Format::from_extension("md"), Format::Markdown);
+	/// assert_eq!(Format::from_extension("XHTML"), Format::Html);
+	/// assert_eq!(Format::from_extension("txt"), Format::Plain);
+	/// ```
+	#[must_use]
+	pub fn from_extension(extension: &str) -> Self {
+		match extension.to_lowercase().as_str() {
+			"md" | "markdown" | "mdown" | "mkd" => Self::Markdown,
+			"html" | "htm" | "xhtml" | "xml" | "svg" => Self::Html,
+			_ => Self::Plain,
+		}
+	}
+
+	/// Splits a document into runs of prose and markup.
+	///
+	/// Returns each run with `true` if it is prose.
+	///
+	/// ```
+	/// # use porcus::format::Format;
+	/// let segments = Format::Markdown.segments("Run `cargo test` now");
+	/// assert_eq!(
+	///     segments,
+	///     vec![("Run ", true), ("`cargo test`", false), (" now", true)]
+	/// );
+	/// ```
+	#[must_use]
+	pub fn segments(self, s: &str) -> Vec<(&str, bool)> {
+		let markup = match self {
+			Self::Plain => Vec::new(),
+			Self::Markdown => markdown_markup(s),
+			Self::Html => html_markup(s),
+		};
+
+		let mut segments = Vec::new();
+		let mut position = 0;
+		for (start, end) in markup {
+			if start > position {
+				segments.push((&s[position..start], true));
+			}
+			if end > start {
+				segments.push((&s[start..end], false));
+			}
+			position = end;
+		}
+		if position < s.len() {
+			segments.push((&s[position..], true));
+		}
+		segments
+	}
+
+	/// Applies a function to the prose of a document, leaving markup unchanged.
+	///
+	/// ```
+	/// # use porcus::format::Format;
+	/// let shouted = Format::Html.map_prose("<b>hi</b> there", str::to_uppercase);
+	/// assert_eq!(shouted, "<b>HI</b> THERE");
+	/// ```
+	pub fn map_prose<F: FnMut(&str) -> String>(self, s: &str, mut f: F) -> String {
+		self.segments(s)
+			.into_iter()
+			.map(
+				|(text, is_prose)| {
+					if is_prose {
+						f(text)
+					} else {
+						text.to_string()
+					}
+				},
+			)
+			.collect::<Vec<String>>()
+			.concat()
+	}
+}
+
+/// Finds byte ranges of Markdown code, URLs and tags, in order.
+fn markdown_markup(s: &str) -> Vec<(usize, usize)> {
+	let mut ranges = Vec::new();
+	let mut fence: Option<&str> = None;
+	let mut line_start = 0;
+
+	for line in s.split_inclusive('\n') {
+		let line_end = line_start + line.len();
+		let trimmed = line.trim_start_matches(' ');
+		let is_fence = line.len() - trimmed.len() <= 3
+			&& fence.map_or_else(
+				|| trimmed.starts_with("```") || trimmed.starts_with("~~~"),
+				|marker| trimmed.starts_with(marker),
+			);
+
+		if fence.is_some() || is_fence {
+			ranges.push((line_start, line_end));
+			if is_fence {
+				fence = match fence {
+					Some(_) => None,
+					None => Some(&trimmed[..3]),
+				};
+			}
+		} else {
+			ranges.extend(
+				markdown_inline_markup(line)
+					.into_iter()
+					.map(|(start, end)| (line_start + start, line_start + end)),
+			);
+		}
+		line_start = line_end;
+	}
+	ranges
+}
+
+/// Finds byte ranges of inline code, link destinations and tags within a line of Markdown.
+fn markdown_inline_markup(line: &str) -> Vec<(usize, usize)> {
+	let bytes = line.as_bytes();
+	let mut ranges = Vec::new();
+	let mut index = 0;
+	while index < bytes.len() {
+		let end = match bytes[index] {
+			b'`' => {
+				let ticks = bytes[index..].iter().take_while(|&&b| b == b'`').count();
+				let marker = &line[index..index + ticks];
+				line[index + ticks..]
+					.find(marker)
+					.map(|offset| index + ticks + offset + ticks)
+			}
+			b']' if bytes.get(index + 1) == Some(&b'(') => {
+				line[index..].find(')').map(|offset| index + offset + 1)
+			}
+			b'<' if line[index + 1..]
+				.starts_with(|c: char| c.is_ascii_alphabetic() || c == '/' || c == '!') =>
+			{
+				line[index..].find('>').map(|offset| index + offset + 1)
+			}
+			_ => None,
+		};
+		match end {
+			Some(end) => {
+				ranges.push((index, end));
+				index = end;
+			}
+			None => index += 1,
+		}
+	}
+	ranges
+}
+
+/// Finds byte ranges of HTML tags, comments, entities and raw elements, in order.
+fn html_markup(s: &str) -> Vec<(usize, usize)> {
+	let bytes = s.as_bytes();
+	let mut tag_end = NextMatch::new(">");
+	let mut closing_tags = HTML_RAW_ELEMENTS
+		.iter()
+		.map(|name| NextMatch::new(&format!("</{}", name)))
+		.collect::<Vec<NextMatch>>();
+	let mut ranges = Vec::new();
+	let mut index = 0;
+	while index < bytes.len() {
+		let end = match bytes[index] {
+			b'<' if s[index..].starts_with("<!--") => Some(
+				s[index..]
+					.find("-->")
+					.map_or(s.len(), |offset| index + offset + 3),
+			),
+			b'<' if s[index + 1..]
+				.starts_with(|c: char| c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?')) =>
+			{
+				tag_end.find(s, index).map(|offset| {
+					let end = offset + 1;
+					raw_element_end(s, index, end, &mut closing_tags, &mut tag_end).unwrap_or(end)
+				})
+			}
+			b'&' => {
+				let name_length = bytes[index + 1..]
+					.iter()
+					.take_while(|&&b| b.is_ascii_alphanumeric() || b == b'#')
+					.count();
+				let semicolon = index + 1 + name_length;
+				(name_length > 0 && bytes.get(semicolon) == Some(&b';')).then_some(semicolon + 1)
+			}
+			_ => None,
+		};
+		match end {
+			Some(end) => {
+				ranges.push((index, end));
+				index = end;
+			}
+			None => index += 1,
+		}
+	}
+	ranges
+}
+
+/// If the tag opens a raw element, finds the end of its closing tag.
+fn raw_element_end(
+	s: &str,
+	tag_start: usize,
+	tag_end: usize,
+	closing_tags: &mut [NextMatch],
+	closing_end: &mut NextMatch,
+) -> Option<usize> {
+	let name = s[tag_start + 1..tag_end - 1]
+		.split(|c: char| c.is_whitespace() || c == '/')
+		.next()?;
+	let element = HTML_RAW_ELEMENTS
+		.iter()
+		.position(|raw| raw.eq_ignore_ascii_case(name))?;
+	if s[..tag_end].ends_with("/>") {
+		return None;
+	}
+	let closing_start = closing_tags[element].find(s, tag_end)?;
+	Some(
+		closing_end
+			.find(s, closing_start)
+			.map_or(s.len(), |offset| offset + 1),
+	)
+}
+
+/// Next match of an ASCII pattern, ignoring ASCII case, in text searched from increasing positions.
+///
+/// The last match is remembered, so that the text is scanned at most once even when most searches
+/// find the same match, or none.
+struct NextMatch {
+	pattern: String,
+	/// Position of the last match, or `usize::MAX` if there was none, once searched.
+	next: Option<usize>,
+}
+
+impl NextMatch {
+	fn new(pattern: &str) -> Self {
+		Self {
+			pattern: String::from(pattern),
+			next: None,
+		}
+	}
+
+	/// Finds the first match at or after a position.
+	fn find(&mut self, s: &str, from: usize) -> Option<usize> {
+		if self.next.is_none_or(|next| next < from) {
+			let pattern = self.pattern.as_bytes();
+			let next = s.as_bytes()[from..]
+				.windows(pattern.len())
+				.position(|window| window.eq_ignore_ascii_case(pattern))
+				.map_or(usize::MAX, |offset| from + offset);
+			self.next = Some(next);
+		}
+		self.next.filter(|&next| next != usize::MAX)
+	}
+}
+
+#[cfg(test)]
+mod test_segments {
+	use super::*;
+
+	fn prose(format: Format, s: &str) -> Vec<&str> {
+		format
+			.segments(s)
+			.into_iter()
+			.filter(|(_, is_prose)| *is_prose)
+			.map(|(text, _)| text)
+			.collect()
+	}
+
+	#[test]
+	fn plain() {
+		assert_eq!(prose(Format::Plain, "a <b> `c`"), vec!["a <b> `c`"]);
+		assert!(prose(Format::Plain, "").is_empty());
+	}
+
+	#[test]
+	fn segments_cover_input() {
+		let inputs = [
+			"# Title\n\n```rust\nfn main() {}\n```\nText [link](http://x.y) `code` <br>\n",
+			"<html><body><p>Hi &amp; bye</p><script>var x = 1;</script></body></html>",
+		];
+		for (format, input) in [Format::Markdown, Format::Html].iter().zip(inputs) {
+			let joined = format
+				.segments(input)
+				.into_iter()
+				.map(|(text, _)| text)
+				.collect::<String>();
+			assert_eq!(joined, input);
+		}
+	}
+
+	#[test]
+	fn markdown_fences() {
+		let s = "Before\n```\ncode here\n```\nAfter\n~~~\n```\n~~~\nEnd";
+		assert_eq!(
+			prose(Format::Markdown, s),
+			vec!["Before\n", "After\n", "End"]
+		);
+	}
+
+	#[test]
+	fn markdown_inline() {
+		let s = "See [the docs](https://example.com) or ``a ` b`` and <https://x.y>.";
+		assert_eq!(
+			prose(Format::Markdown, s),
+			vec!["See [the docs", " or ", " and ", "."]
+		);
+		assert_eq!(prose(Format::Markdown, "1 < 2 > 0"), vec!["1 < 2 > 0"]);
+		assert_eq!(
+			prose(Format::Markdown, "unclosed `tick"),
+			vec!["unclosed `tick"]
+		);
+	}
+
+	#[test]
+	fn html() {
+		let s = "<p title=\"Hi\">One<!-- two --> three&nbsp;four &amp five</p>";
+		assert_eq!(
+			prose(Format::Html, s),
+			vec!["One", " three", "four &amp five"]
+		);
+	}
+
+	#[test]
+	fn html_stray_brackets() {
+		assert_eq!(prose(Format::Html, "1 < 2 > 0"), vec!["1 < 2 > 0"]);
+	}
+
+	#[test]
+	fn html_raw_elements() {
+		let s = "a<PRE class=x>b <i>c</i></pre>d<code/>e<style>f</STYLE>g";
+		assert_eq!(prose(Format::Html, s), vec!["a", "d", "e", "g"]);
+	}
+
+	#[test]
+	fn html_unclosed_runs() {
+		// Scanning the rest of the document at each of these would take minutes.
+		for markup in ["<pre>", "<a", "& ", "&amp", "<code>x</Code", "</pre"] {
+			let s = markup.repeat(100_000);
+			let segments = Format::Html.segments(&s);
+			assert_eq!(
+				segments
+					.into_iter()
+					.map(|(text, _)| text)
+					.collect::<String>(),
+				s
+			);
+		}
+	}
+}
@@ -0,0 +1,377 @@
+//! Pig latin of the spoken form, transcribed in IPA.
+//!
+//! # Usage
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use porcus::ipa::IpaTransformer;
+//! use porcus::lexicon::Lexicon;
+//!
+//! let cmudict = "PIG  P IH1 G\nLATIN  L AE1 T AH0 N\n";
+//! let lexicon = Lexicon::from_cmudict(cmudict.as_bytes()).unwrap();
+//!
+//! let transformer = IpaTransformer::new(lexicon);
+//! assert_eq!(transformer.to_pig_latin("Pig latin"), "ˈɪɡpeɪ ˈætənleɪ");
+//! # }
+//! ```
+
+use crate::lexicon::{Lexicon, PhoneticAlphabet};
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt;
+use phf::phf_map;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Default suffix to append to words starting with a consonant sound, e.g. `nɪks` → `ɪksn`+`eɪ`.
+pub const DEFAULT_IPA_CONSONANT_SUFFIX: &str = "eɪ";
+
+/// Default suffix to append to words starting with a vowel sound, e.g. `ɛɡ` → `ɛɡ`+`weɪ`.
+pub const DEFAULT_IPA_VOWEL_SUFFIX: &str = "weɪ";
+
+const PRIMARY_STRESS: char = 'ˈ';
+const SECONDARY_STRESS: char = 'ˌ';
+
+/// IPA equivalents of ARPABET symbols, in General American, without stress markers.
+///
+/// `AH` and `ER` depend on stress, and are listed with their stressed values.
+const ARPABET_TO_IPA: phf::Map<&'static str, &'static str> = phf_map! {
+	// Vowels
+	"AA" => "ɑ", "AE" => "æ", "AH" => "ʌ", "AO" => "ɔ", "AW" => "aʊ", "AX" => "ə", "AXR" => "ɚ",
+	"AY" => "aɪ", "EH" => "ɛ", "ER" => "ɝ", "EY" => "eɪ", "IH" => "ɪ", "IX" => "ɨ", "IY" => "i",
+	"OW" => "oʊ", "OY" => "ɔɪ", "UH" => "ʊ", "UW" => "u", "UX" => "ʉ",
+	// Consonants
+	"B" => "b", "CH" => "tʃ", "D" => "d", "DH" => "ð", "DX" => "ɾ", "EL" => "l̩", "EM" => "m̩",
+	"EN" => "n̩", "F" => "f", "G" => "ɡ", "HH" => "h", "JH" => "dʒ", "K" => "k", "L" => "l",
+	"M" => "m", "N" => "n", "NG" => "ŋ", "NX" => "ɾ̃", "P" => "p", "Q" => "ʔ", "R" => "ɹ",
+	"S" => "s", "SH" => "ʃ", "T" => "t", "TH" => "θ", "V" => "v", "W" => "w", "WH" => "ʍ",
+	"Y" => "j", "Z" => "z", "ZH" => "ʒ",
+};
+
+/// Stress level of a vowel.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum Stress {
+	/// Main stress of the word, marked `ˈ`.
+	Primary,
+	/// Lesser stress, marked `ˌ`.
+	Secondary,
+	/// No stress.
+	Unstressed,
+}
+
+impl fmt::Display for Stress {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Primary => "primary",
+			Self::Secondary => "secondary",
+			Self::Unstressed => "unstressed",
+		})
+	}
+}
+
+/// Converts an ARPABET phoneme to IPA.
+///
+/// Returns the IPA transcription and, for vowels with a stress marker, the stress level.
+/// Unknown symbols are returned lowercased.
+///
+/// # Examples
+///
+/// ```
+/// # use porcus::ipa::{arpabet_to_ipa, Stress};
+/// assert_eq!(arpabet_to_ipa("SH"), (String::from("ʃ"), None));
+/// assert_eq!(arpabet_to_ipa("AW1"), (String::from("aʊ"), Some(Stress::Primary)));
+/// ```
+///
+/// Unstressed `AH` and `ER` are reduced.
+///
+/// ```
+/// # use porcus::ipa::{arpabet_to_ipa, Stress};
+/// assert_eq!(arpabet_to_ipa("AH1"), (String::from("ʌ"), Some(Stress::Primary)));
+/// assert_eq!(arpabet_to_ipa("AH0"), (String::from("ə"), Some(Stress::Unstressed)));
+/// assert_eq!(arpabet_to_ipa("ER0"), (String::from("ɚ"), Some(Stress::Unstressed)));
+/// ```
+#[must_use]
+pub fn arpabet_to_ipa(phoneme: &str) -> (String, Option<Stress>) {
+	let base = phoneme.trim_end_matches(|c: char| c.is_ascii_digit());
+	let stress = match &phoneme[base.len()..] {
+		"1" => Some(Stress::Primary),
+		"2" => Some(Stress::Secondary),
+		"0" => Some(Stress::Unstressed),
+		_ => None,
+	};
+	let symbol = base.to_uppercase();
+	let ipa = match (symbol.as_str(), stress) {
+		("AH", Some(Stress::Unstressed)) => "ə",
+		("ER", Some(Stress::Unstressed)) => "ɚ",
+		(symbol, _) => ARPABET_TO_IPA.get(symbol).copied().unwrap_or_default(),
+	};
+	if ipa.is_empty() {
+		(base.to_lowercase(), stress)
+	} else {
+		(String::from(ipa), stress)
+	}
+}
+
+/// Converter from text to the IPA transcription of its pig latin pronunciation.
+///
+/// Each word is looked up in a [`Lexicon`]. The consonant sounds it starts with are moved to the
+/// end, then a suffix is appended.
+///
+/// # Examples
+///
+/// ```
+/// # use porcus::ipa::IpaTransformer;
+/// # use porcus::lexicon::Lexicon;
+/// # #[cfg(feature = "std")] {
+/// let cmudict = "EGG  EH1 G\nHOUR  AW1 ER0\nSTRING  S T R IH1 NG\n";
+/// let lexicon = Lexicon::from_cmudict(cmudict.as_bytes()).unwrap();
+/// let transformer = IpaTransformer::new(lexicon);
+/// assert_eq!(transformer.to_pig_latin("egg hour string"), "ˈɛɡweɪ ˈaʊɚweɪ ˈɪŋstɹeɪ");
+/// # }
+/// ```
+///
+/// Stress marks stay with their vowel, in front of its syllable.
+///
+/// ```
+/// # use porcus::ipa::IpaTransformer;
+/// # use porcus::lexicon::Lexicon;
+/// # #[cfg(feature = "std")] {
+/// let cmudict = "BEGIN  B IH0 G IH1 N\nABOUT  AH0 B AW1 T\n";
+/// let lexicon = Lexicon::from_cmudict(cmudict.as_bytes()).unwrap();
+/// let transformer = IpaTransformer::new(lexicon);
+/// assert_eq!(transformer.to_pig_latin("begin about"), "ɪˈɡɪnbeɪ əˈbaʊtweɪ");
+/// # }
+/// ```
+///
+/// Words missing from the lexicon are left as they are.
+///
+/// ```
+/// # use porcus::ipa::IpaTransformer;
+/// # use porcus::lexicon::Lexicon;
+/// let transformer = IpaTransformer::new(Lexicon::default());
+/// assert_eq!(transformer.to_pig_latin("Gazorpazorp!"), "Gazorpazorp!");
+/// ```
+///
+/// Lexicons of IPA transcriptions carry no stress, so no stress marks are output.
+///
+/// ```
+/// # use porcus::ipa::IpaTransformer;
+/// # use porcus::lexicon::Lexicon;
+/// # #[cfg(feature = "std")] {
+/// let lexicon = Lexicon::from_tsv("pig\tpɪɡ\n".as_bytes()).unwrap();
+/// let transformer = IpaTransformer::new(lexicon).with_suffixes("i", "wi");
+/// assert_eq!(transformer.to_pig_latin("pig"), "ɪɡpi");
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IpaTransformer {
+	lexicon: Arc<Lexicon>,
+	consonant_suffix: String,
+	vowel_suffix: String,
+}
+
+impl fmt::Display for IpaTransformer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"IPA Pig Latin <C+{} V+{}>",
+			self.consonant_suffix, self.vowel_suffix
+		)
+	}
+}
+
+impl IpaTransformer {
+	/// Creates a transformer using the default IPA suffixes.
+	pub fn new<L: Into<Arc<Lexicon>>>(lexicon: L) -> Self {
+		Self {
+			lexicon: lexicon.into(),
+			consonant_suffix: String::from(DEFAULT_IPA_CONSONANT_SUFFIX),
+			vowel_suffix: String::from(DEFAULT_IPA_VOWEL_SUFFIX),
+		}
+	}
+
+	/// Sets the suffixes appended to words starting with a consonant or vowel sound.
+	#[must_use]
+	pub fn with_suffixes<Sc, Sv>(mut self, consonant_suffix: Sc, vowel_suffix: Sv) -> Self
+	where
+		Sc: Into<String>,
+		Sv: Into<String>,
+	{
+		self.consonant_suffix = consonant_suffix.into();
+		self.vowel_suffix = vowel_suffix.into();
+		self
+	}
+
+	/// Gets the suffix appended to words starting with a consonant sound.
+	#[must_use]
+	pub const fn consonant_suffix(&self) -> &String {
+		&self.consonant_suffix
+	}
+	/// Gets the suffix appended to words starting with a vowel sound.
+	#[must_use]
+	pub const fn vowel_suffix(&self) -> &String {
+		&self.vowel_suffix
+	}
+	/// Gets the pronunciation dictionary.
+	#[must_use]
+	pub fn lexicon(&self) -> &Lexicon {
+		&self.lexicon
+	}
+
+	/// Returns the IPA transcription of the pig latin translation of a string.
+	pub fn to_pig_latin<S: Into<String>>(&self, s: S) -> String {
+		s.into()
+			.split_word_bounds()
+			.map(|word| {
+				self.word_to_pig_latin(word)
+					.unwrap_or_else(|| word.to_string())
+			})
+			.collect::<Vec<String>>()
+			.concat()
+	}
+
+	fn word_to_pig_latin(&self, word: &str) -> Option<String> {
+		let alphabet = self.lexicon.alphabet();
+		let phonemes = self
+			.lexicon
+			.get(word)?
+			.iter()
+			.map(|phoneme| match alphabet {
+				PhoneticAlphabet::Arpabet => arpabet_to_ipa(phoneme),
+				PhoneticAlphabet::Ipa => (phoneme.clone(), None),
+			})
+			.collect::<Vec<(String, Option<Stress>)>>();
+
+		let onset_length = self.lexicon.onset_length(word)?;
+
+		let suffix = if onset_length == 0 {
+			&self.vowel_suffix
+		} else {
+			&self.consonant_suffix
+		};
+		let mut rotated = phonemes[onset_length..].to_vec();
+		rotated.extend_from_slice(&phonemes[..onset_length]);
+
+		Some(format!("{}{}", with_stress_marks(&rotated), suffix))
+	}
+}
+
+/// Joins phonemes, putting each stress mark before the syllable of its vowel.
+///
+/// Consonants between two vowels go with the following syllable, except the first of a cluster.
+fn with_stress_marks(phonemes: &[(String, Option<Stress>)]) -> String {
+	let mut marks = vec![None; phonemes.len()];
+	let mut previous_vowel = None;
+	for (index, (_, stress)) in phonemes.iter().enumerate() {
+		let Some(stress) = stress else {
+			continue;
+		};
+		let mark = match stress {
+			Stress::Primary => Some(PRIMARY_STRESS),
+			Stress::Secondary => Some(SECONDARY_STRESS),
+			Stress::Unstressed => None,
+		};
+		let consonants = previous_vowel.map_or(index, |previous| index - previous - 1);
+		let syllable_start = match previous_vowel {
+			None => 0,
+			Some(previous) if consonants >= 2 => previous + 2,
+			Some(_) => index - consonants,
+		};
+		marks[syllable_start] = mark.or(marks[syllable_start]);
+		previous_vowel = Some(index);
+	}
+
+	phonemes
+		.iter()
+		.zip(marks)
+		.map(|((phoneme, _), mark)| match mark {
+			Some(mark) => format!("{}{}", mark, phoneme),
+			None => phoneme.clone(),
+		})
+		.collect()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_to_pig_latin {
+	use super::*;
+
+	const CMUDICT: &str = "\
+ONE  W AH1 N
+INSTRUCT  IH2 N S T R AH1 K T
+TELEPHONE  T EH1 L AH0 F OW2 N
+HMM  HH M
+DON'T  D OW1 N T
+";
+
+	fn assert_ipa(input: &str, expected: &str) {
+		let lexicon = Lexicon::from_cmudict(CMUDICT.as_bytes()).unwrap();
+		let transformer = IpaTransformer::new(lexicon);
+		assert_eq!(transformer.to_pig_latin(input), expected);
+	}
+
+	#[test]
+	fn unspelled_onset() {
+		assert_ipa("one", "ˈʌnweɪ");
+	}
+
+	#[test]
+	fn stress_before_cluster() {
+		assert_ipa("instruct", "ˌɪnˈstɹʌktweɪ");
+	}
+
+	#[test]
+	fn secondary_stress() {
+		assert_ipa("Telephone", "ˈɛləˌfoʊnteɪ");
+	}
+
+	#[test]
+	fn no_vowel() {
+		assert_ipa("hmm", "hmeɪ");
+	}
+
+	#[test]
+	fn sentence() {
+		assert_ipa("One, I don't know.", "ˈʌnweɪ, I ˈoʊntdeɪ know.");
+	}
+}
+
+#[cfg(test)]
+mod test_arpabet_to_ipa {
+	use super::*;
+
+	#[test]
+	fn all_symbols_are_ipa() {
+		for (symbol, ipa) in ARPABET_TO_IPA.entries() {
+			assert_ne!(symbol, ipa);
+			assert!(ipa.chars().all(|c| !c.is_ascii_uppercase()), "{}", ipa);
+		}
+	}
+
+	#[test]
+	fn lowercase_symbols() {
+		assert_eq!(arpabet_to_ipa("th"), (String::from("θ"), None));
+		assert_eq!(
+			arpabet_to_ipa("ih2"),
+			(String::from("ɪ"), Some(Stress::Secondary))
+		);
+	}
+
+	#[test]
+	fn unknown_symbol() {
+		assert_eq!(arpabet_to_ipa("XYZ"), (String::from("xyz"), None));
+	}
+
+	#[test]
+	fn case_mapping_changes_length() {
+		assert_eq!(
+			arpabet_to_ipa("ßı1"),
+			(String::from("ßı"), Some(Stress::Primary))
+		);
+		assert_eq!(
+			arpabet_to_ipa("ﬀ1"),
+			(String::from("ﬀ"), Some(Stress::Primary))
+		);
+		assert_eq!(arpabet_to_ipa("ﬀ"), (String::from("ﬀ"), None));
+	}
+}
@@ -1,6 +1,8 @@
 //! Sets of Latin-script characters for classifying vowels vs consonants.
 
-use phf::phf_set;
+use crate::char_type::CharType::{Consonant, Vowel};
+use crate::orthography::Multigraph;
+use phf::{phf_map, phf_set};
 
 /// Latin-script letters which are always vowels.
 ///
@@ -81,3 +83,184 @@ pub const AMBIGUOUS_VOWELS: phf::Set<char> = phf_set! {
 pub const CONSONANT_LIKE_PUNCTUATION: phf::Set<char> = phf_set! {
 	'\'', '’', '＇', '·', '՟', '״', '‧'
 };
+
+/// Hyphens joining the parts of a compound word, e.g. `T-shirt`.
+pub const HYPHENS: phf::Set<char> = phf_set! {
+	'-', '‐', '‑',
+};
+
+/// Elided French and Italian words, written with an apostrophe before the next word, e.g. `l'eau`.
+pub const ELISIONS: phf::Set<&'static str> = phf_set! {
+	// French
+	"c", "d", "j", "l", "m", "n", "s", "t", "qu", "jusqu", "lorsqu", "puisqu", "quoiqu",
+	// Italian
+	"all", "bell", "coll", "dall", "dell", "nell", "quell", "quest", "sant", "sull", "un",
+};
+
+/// Clitics ending English contractions, e.g. `don't`.
+pub const CONTRACTION_CLITICS: phf::Set<&'static str> = phf_set! {
+	"n't", "'d", "'ll", "'m", "'re", "'s", "'ve",
+};
+
+/// Multigraphs of [Czech](crate::orthography::Orthography::Czech) orthography.
+pub const CZECH_MULTIGRAPHS: phf::Map<&'static str, Multigraph> = phf_map! {
+	"ch" => Multigraph::new(Consonant),
+	"dž" => Multigraph::new(Consonant),
+};
+
+/// Length in letters of the longest of the [Czech multigraphs](CZECH_MULTIGRAPHS).
+pub const CZECH_MULTIGRAPH_LENGTH: usize = 2;
+
+/// Multigraphs of [Dutch](crate::orthography::Orthography::Dutch) orthography.
+pub const DUTCH_MULTIGRAPHS: phf::Map<&'static str, Multigraph> = phf_map! {
+	"ij" => Multigraph::new(Vowel),
+	"ch" => Multigraph::new(Consonant),
+	"sch" => Multigraph::new(Consonant),
+};
+
+/// Length in letters of the longest of the [Dutch multigraphs](DUTCH_MULTIGRAPHS).
+pub const DUTCH_MULTIGRAPH_LENGTH: usize = 3;
+
+/// Multigraphs of [English](crate::orthography::Orthography::English) orthography.
+pub const ENGLISH_MULTIGRAPHS: phf::Map<&'static str, Multigraph> = phf_map! {
+	"ch" => Multigraph::new(Consonant),
+	"gh" => Multigraph::new(Consonant),
+	"gu" => Multigraph::before(Consonant, &['e', 'i']),
+	"ph" => Multigraph::new(Consonant),
+	"qu" => Multigraph::new(Consonant),
+	"sh" => Multigraph::new(Consonant),
+	"th" => Multigraph::new(Consonant),
+	"wh" => Multigraph::new(Consonant),
+};
+
+/// Length in letters of the longest of the [English multigraphs](ENGLISH_MULTIGRAPHS).
+pub const ENGLISH_MULTIGRAPH_LENGTH: usize = 2;
+
+/// Multigraphs of [French](crate::orthography::Orthography::French) orthography.
+pub const FRENCH_MULTIGRAPHS: phf::Map<&'static str, Multigraph> = phf_map! {
+	"ch" => Multigraph::new(Consonant),
+	"gn" => Multigraph::new(Consonant),
+	"gu" => Multigraph::before(Consonant, &['e', 'i', 'y']),
+	"ph" => Multigraph::new(Consonant),
+	"qu" => Multigraph::new(Consonant),
+};
+
+/// Length in letters of the longest of the [French multigraphs](FRENCH_MULTIGRAPHS).
+pub const FRENCH_MULTIGRAPH_LENGTH: usize = 2;
+
+/// Multigraphs of [German](crate::orthography::Orthography::German) orthography.
+pub const GERMAN_MULTIGRAPHS: phf::Map<&'static str, Multigraph> = phf_map! {
+	"ch" => Multigraph::new(Consonant),
+	"ph" => Multigraph::new(Consonant),
+	"qu" => Multigraph::new(Consonant),
+	"sch" => Multigraph::new(Consonant),
+};
+
+/// Length in letters of the longest of the [German multigraphs](GERMAN_MULTIGRAPHS).
+pub const GERMAN_MULTIGRAPH_LENGTH: usize = 3;
+
+/// Multigraphs of [Spanish](crate::orthography::Orthography::Spanish) orthography.
+pub const SPANISH_MULTIGRAPHS: phf::Map<&'static str, Multigraph> = phf_map! {
+	"ch" => Multigraph::new(Consonant),
+	"gu" => Multigraph::before(Consonant, &['e', 'i']),
+	"ll" => Multigraph::new(Consonant),
+	"qu" => Multigraph::new(Consonant),
+	"rr" => Multigraph::new(Consonant),
+};
+
+/// Length in letters of the longest of the [Spanish multigraphs](SPANISH_MULTIGRAPHS).
+pub const SPANISH_MULTIGRAPH_LENGTH: usize = 2;
+
+/// Multigraphs of [Welsh](crate::orthography::Orthography::Welsh) orthography.
+///
+/// Welsh also treats the single letters `w` and `y` as vowels.
+pub const WELSH_MULTIGRAPHS: phf::Map<&'static str, Multigraph> = phf_map! {
+	"ch" => Multigraph::new(Consonant),
+	"dd" => Multigraph::new(Consonant),
+	"ff" => Multigraph::new(Consonant),
+	"ng" => Multigraph::new(Consonant),
+	"ll" => Multigraph::new(Consonant),
+	"ph" => Multigraph::new(Consonant),
+	"rh" => Multigraph::new(Consonant),
+	"th" => Multigraph::new(Consonant),
+	"w" => Multigraph::new(Vowel),
+	"y" => Multigraph::new(Vowel),
+};
+
+/// Length in letters of the longest of the [Welsh multigraphs](WELSH_MULTIGRAPHS).
+pub const WELSH_MULTIGRAPH_LENGTH: usize = 2;
+
+/// Consonant clusters which may begin a syllable in the
+/// [generic](crate::orthography::Orthography::Generic) orthography.
+///
+/// Single consonants are not listed, as they are always allowed.
+pub const GENERIC_ONSETS: phf::Set<&'static str> = phf_set! {
+	"bl", "br", "cl", "cr", "dr", "fl", "fr", "gl", "gr", "kl", "kr", "pl", "pr", "tr", "vr",
+	"sc", "sk", "sp", "st",
+};
+
+/// Consonant clusters which may begin a syllable in [Czech](crate::orthography::Orthography::Czech).
+pub const CZECH_ONSETS: phf::Set<&'static str> = phf_set! {
+	"bl", "br", "dr", "dv", "hl", "hr", "hv", "kl", "kr", "kv", "ml", "mn", "pl", "pr", "sk",
+	"sl", "sm", "sn", "sp", "st", "sv", "tr", "tv", "vl", "vr", "zd", "zl", "zn", "zv",
+	"čl", "čt", "čr", "šk", "šl", "šp", "št", "šv", "žď", "žl", "ždr", "skr", "spr", "str",
+	"chl", "chr", "chv", "př", "tř", "kř", "dř", "bř", "stř",
+};
+
+/// Consonant clusters which may begin a syllable in [Dutch](crate::orthography::Orthography::Dutch).
+pub const DUTCH_ONSETS: phf::Set<&'static str> = phf_set! {
+	"bl", "br", "dr", "dw", "fl", "fr", "gl", "gr", "kl", "kn", "kr", "kw", "pl", "pr", "sl",
+	"sm", "sn", "sp", "st", "tr", "tw", "vl", "vr", "wr", "zw", "schr", "spr", "str",
+};
+
+/// Consonant clusters which may begin a syllable in
+/// [English](crate::orthography::Orthography::English).
+pub const ENGLISH_ONSETS: phf::Set<&'static str> = phf_set! {
+	"bl", "br", "cl", "cr", "dr", "dw", "fl", "fr", "gl", "gr", "pl", "pr", "sc", "sk", "sl",
+	"sm", "sn", "sp", "st", "sw", "tr", "tw", "sch", "scr", "shr", "skr", "spl", "spr", "squ",
+	"str", "thr", "phl", "phr", "chr",
+};
+
+/// Consonant clusters which may begin a syllable in
+/// [French](crate::orthography::Orthography::French).
+pub const FRENCH_ONSETS: phf::Set<&'static str> = phf_set! {
+	"bl", "br", "cl", "cr", "dr", "fl", "fr", "gl", "gr", "pl", "pr", "tr", "vr", "phl", "phr",
+	"chr",
+};
+
+/// Consonant clusters which may begin a syllable in
+/// [German](crate::orthography::Orthography::German).
+pub const GERMAN_ONSETS: phf::Set<&'static str> = phf_set! {
+	"bl", "br", "dr", "fl", "fr", "gl", "gn", "gr", "kl", "kn", "kr", "pf", "pl", "pr", "sp",
+	"st", "tr", "zw", "pfl", "pfr", "schl", "schm", "schn", "schr", "schw", "spr", "str",
+};
+
+/// Consonant clusters which may begin a syllable in
+/// [Spanish](crate::orthography::Orthography::Spanish).
+pub const SPANISH_ONSETS: phf::Set<&'static str> = phf_set! {
+	"bl", "br", "cl", "cr", "dr", "fl", "fr", "gl", "gr", "pl", "pr", "tr",
+};
+
+/// Consonant clusters which may begin a syllable in [Welsh](crate::orthography::Orthography::Welsh).
+pub const WELSH_ONSETS: phf::Set<&'static str> = phf_set! {
+	"bl", "br", "cl", "cr", "dr", "fl", "ffr", "gl", "gr", "gw", "pl", "pr", "tr", "chw",
+	"chr", "sb", "sg", "sm", "sn",
+};
+
+/// Names of the letters in English, spelled as pronounced.
+pub const ENGLISH_LETTER_NAMES: phf::Map<char, &'static str> = phf_map! {
+	'a' => "ay", 'b' => "bee", 'c' => "see", 'd' => "dee", 'e' => "ee", 'f' => "ef",
+	'g' => "jee", 'h' => "aitch", 'i' => "eye", 'j' => "jay", 'k' => "kay", 'l' => "el",
+	'm' => "em", 'n' => "en", 'o' => "oh", 'p' => "pee", 'q' => "cue", 'r' => "ar",
+	's' => "ess", 't' => "tee", 'u' => "you", 'v' => "vee", 'w' => "double-you", 'x' => "ex",
+	'y' => "why", 'z' => "zee",
+};
+
+/// Names of the letters in [French](crate::orthography::Orthography::French).
+pub const FRENCH_LETTER_NAMES: phf::Map<char, &'static str> = phf_map! {
+	'a' => "a", 'b' => "bé", 'c' => "cé", 'd' => "dé", 'e' => "eu", 'f' => "effe",
+	'g' => "gé", 'h' => "ache", 'i' => "i", 'j' => "ji", 'k' => "ka", 'l' => "elle",
+	'm' => "emme", 'n' => "enne", 'o' => "o", 'p' => "pé", 'q' => "ku", 'r' => "erre",
+	's' => "esse", 't' => "té", 'u' => "u", 'v' => "vé", 'w' => "double-vé", 'x' => "ixe",
+	'y' => "i-grec", 'z' => "zède",
+};
@@ -0,0 +1,343 @@
+//! Pronunciation dictionaries.
+//!
+//! # Usage
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use porcus::lexicon::Lexicon;
+//! use porcus::PigLatinTransformer;
+//!
+//! let cmudict = "HOUR  AW1 ER0\nHONEST  AA1 N AH0 S T\n";
+//! let lexicon = Lexicon::from_cmudict(cmudict.as_bytes()).unwrap();
+//!
+//! let transformer = PigLatinTransformer::default().with_lexicon(lexicon);
+//! assert_eq!(transformer.to_pig_latin("an honest hour"), "anway honestway hourway");
+//! # }
+//! ```
+
+use crate::char_type::{self, CharType};
+use crate::normalization::lookup_key;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use phf::{phf_map, phf_set};
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+#[cfg(feature = "std")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// ARPABET vowel symbols, without stress markers.
+const ARPABET_VOWELS: phf::Set<&'static str> = phf_set! {
+	"AA", "AE", "AH", "AO", "AW", "AX", "AXR", "AY", "EH", "ER", "EY", "IH", "IX", "IY", "OW",
+	"OY", "UH", "UW", "UX",
+};
+
+/// Plain-letter respellings of ARPABET symbols, without stress markers.
+const ARPABET_RESPELLINGS: phf::Map<&'static str, &'static str> = phf_map! {
+	// Vowels
+	"AA" => "o", "AE" => "a", "AH" => "u", "AO" => "aw", "AW" => "ow", "AX" => "u", "AXR" => "er",
+	"AY" => "y", "EH" => "e", "ER" => "er", "EY" => "ay", "IH" => "i", "IX" => "i", "IY" => "ee",
+	"OW" => "oh", "OY" => "oy", "UH" => "oo", "UW" => "oo", "UX" => "oo",
+	// Consonants
+	"B" => "b", "CH" => "ch", "D" => "d", "DH" => "th", "DX" => "t", "EL" => "ul", "EM" => "um",
+	"EN" => "un", "F" => "f", "G" => "g", "HH" => "h", "JH" => "j", "K" => "k", "L" => "l",
+	"M" => "m", "N" => "n", "NG" => "ng", "NX" => "n", "P" => "p", "Q" => "", "R" => "r",
+	"S" => "s", "SH" => "sh", "T" => "t", "TH" => "th", "V" => "v", "W" => "w", "WH" => "wh",
+	"Y" => "y", "Z" => "z", "ZH" => "zh",
+};
+
+/// Plain-letter respellings of IPA phonemes, as in [`ARPABET_RESPELLINGS`].
+const IPA_RESPELLINGS: phf::Map<&'static str, &'static str> = phf_map! {
+	// Vowels
+	"ɑ" => "o", "æ" => "a", "ʌ" => "u", "ɔ" => "aw", "aʊ" => "ow", "ə" => "u", "ɚ" => "er",
+	"aɪ" => "y", "ɛ" => "e", "ɝ" => "er", "eɪ" => "ay", "ɪ" => "i", "ɨ" => "i", "i" => "ee",
+	"oʊ" => "oh", "ɔɪ" => "oy", "ʊ" => "oo", "u" => "oo", "ʉ" => "oo",
+	// Consonants
+	"b" => "b", "tʃ" => "ch", "d" => "d", "ð" => "th", "ɾ" => "t", "f" => "f", "ɡ" => "g",
+	"g" => "g", "h" => "h", "dʒ" => "j", "k" => "k", "l" => "l", "m" => "m", "n" => "n",
+	"ŋ" => "ng", "p" => "p", "ʔ" => "", "ɹ" => "r", "r" => "r", "s" => "s", "ʃ" => "sh",
+	"t" => "t", "θ" => "th", "v" => "v", "w" => "w", "ʍ" => "wh", "j" => "y", "z" => "z",
+	"ʒ" => "zh",
+};
+
+/// IPA marks which are not phonemes on their own.
+#[cfg(feature = "std")]
+const IPA_SUPRASEGMENTALS: phf::Set<char> = phf_set! {
+	'ˈ', 'ˌ', 'ː', 'ˑ', '.', '|', '‖', '/', '[', ']',
+};
+
+/// Notation used for the phonemes of a lexicon.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PhoneticAlphabet {
+	/// [ARPABET](https://en.wikipedia.org/wiki/ARPABET), as used by CMUdict, e.g. `HH AW1 S`.
+	#[default]
+	Arpabet,
+	/// [International Phonetic Alphabet](https://en.wikipedia.org/wiki/International_Phonetic_Alphabet),
+	/// e.g. `h aʊ s`.
+	Ipa,
+}
+
+impl fmt::Display for PhoneticAlphabet {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Arpabet => "ARPABET",
+			Self::Ipa => "IPA",
+		})
+	}
+}
+
+impl PhoneticAlphabet {
+	/// Checks whether a phoneme is a vowel.
+	///
+	/// ARPABET stress markers are ignored. IPA symbols are classified by their first character,
+	/// with `y` and its variants counted as vowels.
+	///
+	/// ```
+	/// # use porcus::lexicon::PhoneticAlphabet;
+	/// assert!(PhoneticAlphabet::Arpabet.is_vowel("AW1"));
+	/// assert!(!PhoneticAlphabet::Arpabet.is_vowel("Y"));
+	/// assert!(PhoneticAlphabet::Ipa.is_vowel("aʊ"));
+	/// assert!(PhoneticAlphabet::Ipa.is_vowel("y"));
+	/// assert!(!PhoneticAlphabet::Ipa.is_vowel("j"));
+	/// ```
+	#[must_use]
+	pub fn is_vowel(self, phoneme: &str) -> bool {
+		match self {
+			Self::Arpabet => {
+				let symbol = phoneme
+					.trim_end_matches(|c: char| c.is_ascii_digit())
+					.to_uppercase();
+				ARPABET_VOWELS.contains(symbol.as_str())
+			}
+			Self::Ipa => matches!(
+				char_type::get_char_type_at(&[phoneme], 0),
+				CharType::Vowel | CharType::Ambiguous
+			),
+		}
+	}
+
+	/// Spells a phoneme in plain letters, as in a pronunciation guide.
+	///
+	/// Returns `None` for unknown phonemes.
+	///
+	/// ```
+	/// # use porcus::lexicon::PhoneticAlphabet;
+	/// assert_eq!(PhoneticAlphabet::Arpabet.respell("AH1"), Some("u"));
+	/// assert_eq!(PhoneticAlphabet::Ipa.respell("ʃ"), Some("sh"));
+	/// assert_eq!(PhoneticAlphabet::Arpabet.respell("XYZ"), None);
+	/// ```
+	#[must_use]
+	pub fn respell(self, phoneme: &str) -> Option<&'static str> {
+		match self {
+			Self::Arpabet => {
+				let symbol = phoneme
+					.trim_end_matches(|c: char| c.is_ascii_digit())
+					.to_uppercase();
+				ARPABET_RESPELLINGS.get(symbol.as_str()).copied()
+			}
+			Self::Ipa => IPA_RESPELLINGS.get(phoneme).copied(),
+		}
+	}
+}
+
+/// Dictionary mapping words to their pronunciation.
+///
+/// Words are matched case-insensitively. Only the first pronunciation of each word is kept.
+#[derive(Debug, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Lexicon {
+	alphabet: PhoneticAlphabet,
+	entries: BTreeMap<String, Vec<String>>,
+}
+
+impl Lexicon {
+	/// Creates an empty lexicon.
+	#[must_use]
+	pub const fn new(alphabet: PhoneticAlphabet) -> Self {
+		Self {
+			alphabet,
+			entries: BTreeMap::new(),
+		}
+	}
+
+	/// Reads a lexicon in [CMUdict](https://github.com/cmusphinx/cmudict) format.
+	///
+	/// Each line holds a word followed by its ARPABET phonemes, separated by whitespace.
+	/// Alternate pronunciations (`WORD(2)`) and comments (`;;;` lines, `#` to end of line) are
+	/// ignored.
+	///
+	/// ```
+	/// # use porcus::lexicon::Lexicon;
+	/// let cmudict = ";;; comment\nONE  W AH1 N\nONE(2)  HH W AH1 N\n";
+	/// let lexicon = Lexicon::from_cmudict(cmudict.as_bytes()).unwrap();
+	/// assert_eq!(lexicon.get("one"), Some(&["W", "AH1", "N"].map(String::from)[..]));
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if reading fails or the input is not valid UTF-8.
+	#[cfg(feature = "std")]
+	pub fn from_cmudict<R: BufRead>(reader: R) -> io::Result<Self> {
+		let mut lexicon = Self::new(PhoneticAlphabet::Arpabet);
+		for line in reader.lines() {
+			let line = line?;
+			if line.starts_with(";;;") {
+				continue;
+			}
+			let line = line.split('#').next().unwrap_or_default();
+			let mut fields = line.split_whitespace();
+			if let Some(word) = fields.next() {
+				if word.ends_with(')') {
+					continue;
+				}
+				lexicon.insert(word, fields.map(String::from).collect());
+			}
+		}
+		Ok(lexicon)
+	}
+
+	/// Reads a lexicon of tab-separated IPA transcriptions.
+	///
+	/// Each line holds a word, a tab, and its phonemes. Phonemes are separated by spaces, or
+	/// taken one grapheme at a time if the transcription has no spaces. Stress and length marks
+	/// are ignored.
+	///
+	/// ```
+	/// # use porcus::lexicon::Lexicon;
+	/// let tsv = "hour\taʊ ɚ\none\twʌn\n";
+	/// let lexicon = Lexicon::from_tsv(tsv.as_bytes()).unwrap();
+	/// assert_eq!(lexicon.get("Hour"), Some(&["aʊ", "ɚ"].map(String::from)[..]));
+	/// assert_eq!(lexicon.get("one"), Some(&["w", "ʌ", "n"].map(String::from)[..]));
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if reading fails or the input is not valid UTF-8.
+	#[cfg(feature = "std")]
+	pub fn from_tsv<R: BufRead>(reader: R) -> io::Result<Self> {
+		let mut lexicon = Self::new(PhoneticAlphabet::Ipa);
+		for line in reader.lines() {
+			let line = line?;
+			let mut fields = line.splitn(2, '\t');
+			if let (Some(word), Some(transcription)) = (fields.next(), fields.next()) {
+				let transcription = transcription
+					.chars()
+					.filter(|c| !IPA_SUPRASEGMENTALS.contains(c))
+					.collect::<String>();
+				let transcription = transcription.trim();
+				let phonemes = if transcription.contains(' ') {
+					transcription.split_whitespace().map(String::from).collect()
+				} else {
+					transcription.graphemes(true).map(String::from).collect()
+				};
+				lexicon.insert(word.trim(), phonemes);
+			}
+		}
+		Ok(lexicon)
+	}
+
+	/// Adds a word, unless it is already listed.
+	pub fn insert<S: AsRef<str>>(&mut self, word: S, phonemes: Vec<String>) {
+		let word = lookup_key(word.as_ref());
+		if !word.is_empty() && !phonemes.is_empty() {
+			self.entries.entry(word).or_insert(phonemes);
+		}
+	}
+
+	/// Gets the phonemes of a word.
+	#[must_use]
+	pub fn get(&self, word: &str) -> Option<&[String]> {
+		self.entries.get(&lookup_key(word)).map(Vec::as_slice)
+	}
+
+	/// Gets the notation used for phonemes.
+	#[must_use]
+	pub const fn alphabet(&self) -> PhoneticAlphabet {
+		self.alphabet
+	}
+
+	/// Gets the number of words listed.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	/// Checks whether no words are listed.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Counts the consonant phonemes a word starts with.
+	///
+	/// Returns `None` if the word is not listed.
+	///
+	/// ```
+	/// # use porcus::lexicon::Lexicon;
+	/// # #[cfg(feature = "std")] {
+	/// let cmudict = "HOUR  AW1 ER0\nSTRING  S T R IH1 NG\n";
+	/// let lexicon = Lexicon::from_cmudict(cmudict.as_bytes()).unwrap();
+	/// assert_eq!(lexicon.onset_length("hour"), Some(0));
+	/// assert_eq!(lexicon.onset_length("string"), Some(3));
+	/// assert_eq!(lexicon.onset_length("unknown"), None);
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn onset_length(&self, word: &str) -> Option<usize> {
+		self.get(word).map(|phonemes| {
+			phonemes
+				.iter()
+				.take_while(|phoneme| !self.alphabet.is_vowel(phoneme))
+				.count()
+		})
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_from_cmudict {
+	use super::*;
+
+	#[test]
+	fn keeps_first_pronunciation() {
+		let cmudict = "TOMATO  T AH0 M EY1 T OW2\nTOMATO(1)  T AH0 M AA1 T OW2\n";
+		let lexicon = Lexicon::from_cmudict(cmudict.as_bytes()).unwrap();
+		assert_eq!(lexicon.len(), 1);
+		assert_eq!(lexicon.get("tomato").unwrap()[3], "EY1");
+	}
+
+	#[test]
+	fn skips_comments() {
+		let cmudict = ";;; header\n\nhour aw1 er0 # comment\n";
+		let lexicon = Lexicon::from_cmudict(cmudict.as_bytes()).unwrap();
+		assert_eq!(lexicon.len(), 1);
+		assert_eq!(lexicon.get("HOUR").unwrap(), &["aw1", "er0"]);
+	}
+
+	#[test]
+	fn rejects_invalid_utf8() {
+		assert!(Lexicon::from_cmudict(&b"CAF\xc9  K AE0 F EY1\n"[..]).is_err());
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_onset_length {
+	use super::*;
+
+	#[test]
+	fn arpabet() {
+		let cmudict = "ONE  W AH1 N\nYES  Y EH1 S\nAN  AE1 N\n";
+		let lexicon = Lexicon::from_cmudict(cmudict.as_bytes()).unwrap();
+		assert_eq!(lexicon.onset_length("one"), Some(1));
+		assert_eq!(lexicon.onset_length("yes"), Some(1));
+		assert_eq!(lexicon.onset_length("an"), Some(0));
+	}
+
+	#[test]
+	fn ipa() {
+		let tsv = "hour\tˈaʊ.ɚ\nyes\tjɛs\nscream\tskɹiːm\n";
+		let lexicon = Lexicon::from_tsv(tsv.as_bytes()).unwrap();
+		assert_eq!(lexicon.onset_length("hour"), Some(0));
+		assert_eq!(lexicon.onset_length("yes"), Some(1));
+		assert_eq!(lexicon.onset_length("scream"), Some(3));
+	}
+}
@@ -26,6 +26,13 @@
 //! let transformer = PigLatinTransformer::new("eɪ", "weɪ");
 //! assert_eq!(transformer.to_pig_latin("ə stɹɪŋ"), "əweɪ ɪŋstɹeɪ");
 //! ```
+//!
+//! Without the default `std` feature, the library is `no_std` and only needs `alloc`. The word
+//! [`cache`] and the readers of lexicons and exceptions are then unavailable.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 /// Default suffix to append to words starting with a consonant, e.g. `nix` → `ixn`+`ay`.
 pub const DEFAULT_CONSONANT_SUFFIX: &str = "ay";
@@ -33,9 +40,29 @@ pub const DEFAULT_CONSONANT_SUFFIX: &str = "ay";
 /// Default suffix to append to words starting with a vowel, e.g. `egg` → `egg`+`way`.
 pub const DEFAULT_VOWEL_SUFFIX: &str = "way";
 
+pub mod acronym;
+pub mod ascii;
+#[cfg(feature = "std")]
+pub mod cache;
 pub mod case;
 pub mod char_type;
+pub mod compound;
+pub mod dialect;
+pub mod encoding;
+pub mod exceptions;
+pub mod format;
+pub mod ipa;
 pub mod latin;
+pub mod lexicon;
+pub mod normalization;
+pub mod number;
+pub mod orthography;
+pub mod style;
+pub mod syllable;
+pub mod token;
 
 mod pig_latin;
-pub use crate::pig_latin::PigLatinTransformer;
+pub use crate::pig_latin::{PigLatinTransformer, Span};
+
+#[cfg(feature = "python")]
+mod python;
@@ -1,11 +1,49 @@
-use clap::{App, Arg};
+mod cli;
+
+use clap::{App, Arg, ArgMatches};
+use cli::config::{self, Config};
+use cli::error::CliError;
+use cli::files::{self, Destination, Filter, Processor};
+use porcus::acronym::AcronymPolicy;
+use porcus::cache::CachedTransformer;
+use porcus::case::Locale;
+use porcus::compound::{Contraction, Elision, Hyphenation};
+use porcus::dialect::Dialect;
+use porcus::encoding::Encoding;
+use porcus::exceptions::Exceptions;
+use porcus::format::Format;
+use porcus::ipa::{IpaTransformer, DEFAULT_IPA_CONSONANT_SUFFIX, DEFAULT_IPA_VOWEL_SUFFIX};
+use porcus::lexicon::Lexicon;
+use porcus::normalization::Normalization;
+use porcus::number::Language;
+use porcus::orthography::Orthography;
+use porcus::token::TokenPolicy;
 use porcus::{PigLatinTransformer, DEFAULT_CONSONANT_SUFFIX, DEFAULT_VOWEL_SUFFIX};
-use std::io::{self, Write};
+use std::fs::File;
+use std::io::{BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Arc;
 
 fn main() {
 	let matches = App::new("porcus")
 		.version("0.1.0")
-		.about("Transforms standard input to pig latin")
+		.about("Transforms files or standard input to pig latin")
+		.arg(
+			Arg::with_name("config")
+				.long("config")
+				.takes_value(true)
+				.value_name("FILE")
+				.help("configuration file [default: porcus.toml in this or a parent directory, else in the user configuration directory]"),
+		)
+		.arg(
+			Arg::with_name("profile")
+				.short("p")
+				.long("profile")
+				.takes_value(true)
+				.value_name("NAME")
+				.help("profile from the configuration file [default: default]"),
+		)
 		.arg(
 			Arg::with_name("consonant_suffix")
 				.short("c")
@@ -20,32 +58,418 @@ fn main() {
 				.default_value(DEFAULT_VOWEL_SUFFIX)
 				.help("suffix for words starting with a vowel"),
 		)
+		.arg(
+			Arg::with_name("dialect")
+				.short("d")
+				.long("dialect")
+				.possible_values(Dialect::PRESETS)
+				.default_value("standard")
+				.help("rule variant for moving consonants"),
+		)
+		.arg(
+			Arg::with_name("orthography")
+				.long("orthography")
+				.possible_values(Orthography::NAMES)
+				.default_value("generic")
+				.help("spelling conventions for letters moved as a unit"),
+		)
+		.arg(
+			Arg::with_name("acronyms")
+				.long("acronyms")
+				.possible_values(AcronymPolicy::NAMES)
+				.default_value("transform")
+				.help("how acronyms such as TV are translated"),
+		)
+		.arg(
+			Arg::with_name("tokens")
+				.long("tokens")
+				.possible_values(TokenPolicy::NAMES)
+				.default_value("split")
+				.help("keeps URLs, emails, paths, handles, hashtags, shortcodes and versions unchanged"),
+		)
+		.arg(
+			Arg::with_name("numbers")
+				.long("numbers")
+				.possible_values(Language::NAMES)
+				.takes_value(true)
+				.value_name("LANGUAGE")
+				.help("spells out numbers in this language before translating them"),
+		)
+		.arg(
+			Arg::with_name("locale")
+				.long("locale")
+				.possible_values(Locale::NAMES)
+				.default_value("root")
+				.help("language rules for matching case"),
+		)
+		.arg(
+			Arg::with_name("sentence_capitalization")
+				.long("sentence-capitalization")
+				.help("capitalizes sentences like the input, and keeps the capital of proper nouns"),
+		)
+		.arg(
+			Arg::with_name("normalization")
+				.long("normalization")
+				.possible_values(Normalization::NAMES)
+				.default_value("none")
+				.help("Unicode normalization form of the output"),
+		)
+		.arg(
+			Arg::with_name("hyphens")
+				.long("hyphens")
+				.possible_values(Hyphenation::NAMES)
+				.default_value("per-part")
+				.help("translates hyphenated compounds per part or as a whole word"),
+		)
+		.arg(
+			Arg::with_name("elisions")
+				.long("elisions")
+				.possible_values(Elision::NAMES)
+				.default_value("rotate")
+				.help("rotates, attaches or separates elided words such as l' in l'eau"),
+		)
+		.arg(
+			Arg::with_name("contractions")
+				.long("contractions")
+				.possible_values(Contraction::NAMES)
+				.default_value("whole")
+				.help("translates contractions such as don't as a whole word or keeps the clitic"),
+		)
+		.arg(
+			Arg::with_name("lexicon")
+				.long("lexicon")
+				.takes_value(true)
+				.value_name("FILE")
+				.help("pronunciation dictionary, in CMUdict format or as IPA in a .tsv file"),
+		)
+		.arg(
+			Arg::with_name("exceptions")
+				.long("exceptions")
+				.takes_value(true)
+				.value_name("FILE")
+				.help("words with a fixed translation, one per line, with a tab and the translation or alone to keep them"),
+		)
+		.arg(
+			Arg::with_name("cache")
+				.long("cache")
+				.takes_value(true)
+				.value_name("N")
+				.validator(|size| size.parse::<usize>().map(|_| ()).map_err(|error| error.to_string()))
+				.help("remembers the translations of up to N recently seen words"),
+		)
+		.arg(
+			Arg::with_name("ipa")
+				.long("ipa")
+				.help("outputs the pronunciation in IPA"),
+		)
+		.arg(
+			Arg::with_name("files")
+				.multiple(true)
+				.value_name("FILE")
+				.help("files to transform, or - for standard input [default: standard input]"),
+		)
+		.arg(
+			Arg::with_name("output")
+				.short("o")
+				.long("output")
+				.takes_value(true)
+				.value_name("OUTPUT")
+				.conflicts_with("in_place")
+				.help("writes all output to this file instead of standard output"),
+		)
+		.arg(
+			Arg::with_name("in_place")
+				.long("in-place")
+				.takes_value(true)
+				.min_values(0)
+				.require_equals(true)
+				.value_name("SUFFIX")
+				.help("overwrites each file, keeping a backup with the suffix if given"),
+		)
+		.arg(
+			Arg::with_name("recursive")
+				.short("r")
+				.long("recursive")
+				.takes_value(true)
+				.multiple(true)
+				.number_of_values(1)
+				.value_name("DIR")
+				.help("transforms all files in a directory tree"),
+		)
+		.arg(
+			Arg::with_name("include")
+				.long("include")
+				.takes_value(true)
+				.multiple(true)
+				.number_of_values(1)
+				.value_name("GLOB")
+				.help("only transforms files in directories matching this pattern"),
+		)
+		.arg(
+			Arg::with_name("exclude")
+				.long("exclude")
+				.takes_value(true)
+				.multiple(true)
+				.number_of_values(1)
+				.value_name("GLOB")
+				.help("skips files in directories matching this pattern"),
+		)
+		.arg(
+			Arg::with_name("format")
+				.short("f")
+				.long("format")
+				.possible_values(Format::NAMES)
+				.takes_value(true)
+				.help("markup format [default: from file extension, plain for standard input]"),
+		)
+		.arg(
+			Arg::with_name("encoding")
+				.long("encoding")
+				.possible_values(Encoding::NAMES)
+				.takes_value(true)
+				.help("character encoding [default: UTF-16 if there is a byte order mark, else UTF-8]"),
+		)
+		.arg(
+			Arg::with_name("jobs")
+				.short("j")
+				.long("jobs")
+				.takes_value(true)
+				.value_name("N")
+				.default_value("1")
+				.validator(|jobs| jobs.parse::<usize>().map(|_| ()).map_err(|error| error.to_string()))
+				.help("transforms large inputs on N threads, split at line breaks, or one per CPU with 0"),
+		)
 		.get_matches();
 
-	let consonant_suffix = matches
-		.value_of("consonant_suffix")
-		.expect("Consonant suffix not found in args");
-	let vowel_suffix = matches
-		.value_of("vowel_suffix")
-		.expect("Vowel suffix not found in args");
+	let config_path = matches
+		.value_of("config")
+		.map(PathBuf::from)
+		.or_else(config::find);
+	let config = match config_path.as_deref().map(Config::read) {
+		Some(Ok(config)) => config,
+		Some(Err(error)) => exit_with_error(&error),
+		None => Config::default(),
+	};
+	let profile = config
+		.profile(matches.value_of("profile"))
+		.unwrap_or_else(|error| exit_with_error(&error));
+
+	let dialect = explicit_value(&matches, "dialect")
+		.and_then(Dialect::from_preset)
+		.or(profile.dialect)
+		.unwrap_or_default();
+	let orthography = explicit_value(&matches, "orthography")
+		.and_then(Orthography::from_name)
+		.or(profile.orthography)
+		.unwrap_or_default();
 
-	let transformer = PigLatinTransformer::new(consonant_suffix, vowel_suffix);
+	let acronym_policy = explicit_value(&matches, "acronyms")
+		.and_then(AcronymPolicy::from_name)
+		.or(profile.acronym_policy)
+		.unwrap_or_default();
+
+	let token_policy = explicit_value(&matches, "tokens")
+		.and_then(TokenPolicy::from_name)
+		.or(profile.token_policy)
+		.unwrap_or_default();
+
+	let locale = explicit_value(&matches, "locale")
+		.and_then(Locale::from_name)
+		.or(profile.locale)
+		.unwrap_or_default();
+	let sentence_capitalization =
+		matches.is_present("sentence_capitalization") || profile.sentence_capitalization;
+	let normalization = explicit_value(&matches, "normalization")
+		.and_then(Normalization::from_name)
+		.or(profile.normalization)
+		.unwrap_or_default();
+	let hyphenation = explicit_value(&matches, "hyphens")
+		.and_then(Hyphenation::from_name)
+		.or(profile.hyphenation)
+		.unwrap_or_default();
+	let elision = explicit_value(&matches, "elisions")
+		.and_then(Elision::from_name)
+		.or(profile.elision)
+		.unwrap_or_default();
+	let contraction = explicit_value(&matches, "contractions")
+		.and_then(Contraction::from_name)
+		.or(profile.contraction)
+		.unwrap_or_default();
+	let numbers = matches
+		.value_of("numbers")
+		.and_then(Language::from_name)
+		.or(profile.numbers);
+
+	let lexicon_path = matches
+		.value_of("lexicon")
+		.map(PathBuf::from)
+		.or(profile.lexicon);
+	let lexicon = match lexicon_path.as_deref().map(read_lexicon) {
+		Some(Ok(lexicon)) => Some(Arc::new(lexicon)),
+		Some(Err(error)) => exit_with_error(&error),
+		None => None,
+	};
+
+	let mut exceptions = profile.exceptions;
+	if let Some(path) = matches.value_of("exceptions") {
+		exceptions.extend(
+			read_exceptions(Path::new(path)).unwrap_or_else(|error| exit_with_error(&error)),
+		);
+	}
 
-	loop {
-		let mut input = String::new();
-		let read_size = io::stdin()
-			.read_line(&mut input)
-			.expect("Failed to read line");
+	let ipa = matches.is_present("ipa") || profile.ipa;
+	let (default_consonant_suffix, default_vowel_suffix) = if ipa {
+		(DEFAULT_IPA_CONSONANT_SUFFIX, DEFAULT_IPA_VOWEL_SUFFIX)
+	} else {
+		(DEFAULT_CONSONANT_SUFFIX, DEFAULT_VOWEL_SUFFIX)
+	};
+	let consonant_suffix = explicit_value(&matches, "consonant_suffix")
+		.or(profile.consonant_suffix.as_deref())
+		.unwrap_or(default_consonant_suffix);
+	let vowel_suffix = explicit_value(&matches, "vowel_suffix")
+		.or(profile.vowel_suffix.as_deref())
+		.unwrap_or(default_vowel_suffix);
 
-		if read_size == 0 {
-			break;
+	let jobs = matches
+		.value_of("jobs")
+		.and_then(|jobs| jobs.parse().ok())
+		.unwrap_or(1);
+	if jobs != 1 {
+		rayon::ThreadPoolBuilder::new()
+			.num_threads(jobs)
+			.build_global()
+			.unwrap_or_else(|error| exit_with_error(&CliError::ThreadPool(error)));
+	}
+
+	let transform: Box<dyn Fn(&str) -> String + Sync> = if ipa {
+		let lexicon = lexicon.unwrap_or_else(|| exit_with_error(&CliError::IpaWithoutLexicon));
+		let transformer =
+			IpaTransformer::new(lexicon).with_suffixes(consonant_suffix, vowel_suffix);
+		Box::new(move |input| normalization.normalize(transformer.to_pig_latin(input)))
+	} else {
+		let mut transformer = PigLatinTransformer::new(consonant_suffix, vowel_suffix)
+			.with_dialect(dialect)
+			.with_orthography(orthography)
+			.with_acronym_policy(acronym_policy)
+			.with_token_policy(token_policy)
+			.with_locale(locale)
+			.with_sentence_capitalization(sentence_capitalization)
+			.with_normalization(normalization)
+			.with_hyphenation(hyphenation)
+			.with_elision(elision)
+			.with_contraction(contraction)
+			.with_exceptions(exceptions);
+		if let Some(language) = numbers {
+			transformer = transformer.with_numbers(language);
+		}
+		if let Some(lexicon) = lexicon {
+			transformer = transformer.with_lexicon(lexicon);
 		}
+		match matches.value_of("cache").and_then(|size| size.parse().ok()) {
+			Some(size) if size > 0 => {
+				let transformer = CachedTransformer::new(transformer, size);
+				Box::new(move |input| transformer.to_pig_latin(input))
+			}
+			_ => Box::new(move |input| transformer.to_pig_latin(input)),
+		}
+	};
+
+	let processor = Processor {
+		transform: &transform,
+		format: matches
+			.value_of("format")
+			.and_then(Format::from_name)
+			.or(profile.format),
+		encoding: matches
+			.value_of("encoding")
+			.and_then(Encoding::from_name)
+			.or(profile.encoding),
+		jobs: if jobs == 1 {
+			1
+		} else {
+			rayon::current_num_threads()
+		},
+	};
+	let destination = if matches.is_present("in_place") {
+		Destination::InPlace(matches.value_of("in_place").map(String::from))
+	} else if let Some(output) = matches.value_of("output") {
+		Destination::File(PathBuf::from(output))
+	} else {
+		Destination::Stdout
+	};
 
-		let pig_latin = transformer.to_pig_latin(input);
-		if io::stdout().write(pig_latin.as_bytes()).is_err() {
-			break;
+	let values = |name| {
+		matches
+			.values_of(name)
+			.map_or_else(Vec::new, Iterator::collect::<Vec<&str>>)
+	};
+	let filter = Filter::new(&values("include"), &values("exclude"))
+		.unwrap_or_else(|error| exit_with_error(&error));
+
+	let mut failed = false;
+	let mut report = |error: CliError| {
+		eprintln!("porcus: {}", error);
+		failed = true;
+	};
+	let inputs =
+		files::collect_inputs(&values("files"), &values("recursive"), &filter, &mut report);
+
+	if let Destination::InPlace(backup_suffix) = &destination {
+		for input in &inputs {
+			if let Err(error) = processor.edit_in_place(input, backup_suffix.as_deref()) {
+				report(error);
+			}
+		}
+	} else {
+		let mut output = files::open_output(&destination, &inputs)
+			.unwrap_or_else(|error| exit_with_error(&error));
+		for input in &inputs {
+			match processor.copy(input, &mut output) {
+				Err(error) if error.is_broken_pipe() => break,
+				Err(error) => report(error),
+				Ok(()) => {}
+			}
+		}
+		if let Err(error) = output.flush() {
+			let error = CliError::stdio(error);
+			if !error.is_broken_pipe() {
+				report(error);
+			}
 		}
 	}
 
-	io::stdout().flush().expect("Failed to flush stdout buffer");
+	process::exit(i32::from(failed));
+}
+
+fn exit_with_error(error: &CliError) -> ! {
+	eprintln!("porcus: {}", error);
+	process::exit(1);
+}
+
+fn read_lexicon(path: &Path) -> Result<Lexicon, CliError> {
+	let file = File::open(path).map_err(|error| CliError::io(path, error))?;
+	let reader = BufReader::new(file);
+	let is_tsv = Path::new(path)
+		.extension()
+		.is_some_and(|extension| extension.eq_ignore_ascii_case("tsv"));
+	if is_tsv {
+		Lexicon::from_tsv(reader)
+	} else {
+		Lexicon::from_cmudict(reader)
+	}
+	.map_err(|error| CliError::io(path, error))
+}
+
+fn read_exceptions(path: &Path) -> Result<Exceptions, CliError> {
+	let file = File::open(path).map_err(|error| CliError::io(path, error))?;
+	Exceptions::from_tsv(BufReader::new(file)).map_err(|error| CliError::io(path, error))
+}
+
+/// Gets the value of an argument only if it was passed, ignoring its default.
+fn explicit_value<'a>(matches: &'a ArgMatches, name: &str) -> Option<&'a str> {
+	if matches.occurrences_of(name) > 0 {
+		matches.value_of(name)
+	} else {
+		None
+	}
 }
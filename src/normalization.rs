@@ -0,0 +1,90 @@
+//! Unicode normalization of the output.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::normalization::Normalization;
+//! use porcus::PigLatinTransformer;
+//!
+//! let transformer = PigLatinTransformer::default().with_normalization(Normalization::Nfc);
+//! assert_eq!(transformer.to_pig_latin("cafe\u{301}"), "afécay");
+//! ```
+
+use alloc::string::String;
+use core::fmt;
+use unicode_normalization::UnicodeNormalization;
+
+/// Normalization form applied to the output.
+///
+/// Words are classified the same way in all forms, so inputs which only differ by normalization
+/// give canonically equivalent outputs.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Normalization {
+	/// The output is not normalized. Text which is not translated, such as spaces, punctuation or
+	/// words in other scripts, is left byte for byte as it was in the input.
+	#[default]
+	None,
+	/// Canonical composition, e.g. `é` as a single character.
+	Nfc,
+	/// Canonical decomposition, e.g. `é` as `e` and a combining accent.
+	Nfd,
+	/// Compatibility composition, which also replaces variants such as `ﬁ` or `²`.
+	Nfkc,
+}
+
+impl fmt::Display for Normalization {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::None => "none",
+			Self::Nfc => "nfc",
+			Self::Nfd => "nfd",
+			Self::Nfkc => "nfkc",
+		})
+	}
+}
+
+impl Normalization {
+	/// Names of all forms, as accepted by [`Normalization::from_name`].
+	pub const NAMES: &'static [&'static str] = &["none", "nfc", "nfd", "nfkc"];
+
+	/// Gets a form by name.
+	///
+	/// Returns `None` for unknown names.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"none" => Some(Self::None),
+			"nfc" => Some(Self::Nfc),
+			"nfd" => Some(Self::Nfd),
+			"nfkc" => Some(Self::Nfkc),
+			_ => None,
+		}
+	}
+
+	/// Normalizes a string to this form.
+	///
+	/// ```
+	/// # use porcus::normalization::Normalization;
+	/// assert_eq!(Normalization::Nfd.normalize("é"), "e\u{301}");
+	/// assert_eq!(Normalization::Nfkc.normalize("ﬁ²"), "fi2");
+	/// assert_eq!(Normalization::None.normalize("e\u{301}"), "e\u{301}");
+	/// ```
+	#[must_use]
+	pub fn normalize<S: Into<String>>(self, s: S) -> String {
+		let s = s.into();
+		match self {
+			Self::None => s,
+			Self::Nfc => s.nfc().collect(),
+			Self::Nfd => s.nfd().collect(),
+			Self::Nfkc => s.nfkc().collect(),
+		}
+	}
+}
+
+/// Gets the key under which a word is looked up in a word list, so that words match regardless of
+/// case and normalization.
+pub(crate) fn lookup_key(word: &str) -> String {
+	Normalization::Nfc.normalize(word.to_lowercase())
+}
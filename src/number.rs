@@ -0,0 +1,664 @@
+//! Numbers spelled out as words.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::number::{self, Language};
+//! use porcus::PigLatinTransformer;
+//!
+//! assert_eq!(number::verbalize("42", Language::English).as_deref(), Some("forty-two"));
+//!
+//! let transformer = PigLatinTransformer::default().with_numbers(Language::English);
+//! assert_eq!(transformer.to_pig_latin("42 µm"), "ortyfay-otway µm");
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use unicode_segmentation::UnicodeSegmentation;
+
+const ENGLISH_UNITS: [&str; 20] = [
+	"zero",
+	"one",
+	"two",
+	"three",
+	"four",
+	"five",
+	"six",
+	"seven",
+	"eight",
+	"nine",
+	"ten",
+	"eleven",
+	"twelve",
+	"thirteen",
+	"fourteen",
+	"fifteen",
+	"sixteen",
+	"seventeen",
+	"eighteen",
+	"nineteen",
+];
+const ENGLISH_TENS: [&str; 10] = [
+	"", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const ENGLISH_SCALES: [&str; 7] = [
+	"",
+	"thousand",
+	"million",
+	"billion",
+	"trillion",
+	"quadrillion",
+	"quintillion",
+];
+const FRENCH_UNITS: [&str; 20] = [
+	"zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+	"douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit", "dix-neuf",
+];
+const FRENCH_TENS: [&str; 7] = [
+	"",
+	"dix",
+	"vingt",
+	"trente",
+	"quarante",
+	"cinquante",
+	"soixante",
+];
+/// Names of powers of a thousand from a million, which are nouns taking a plural.
+const FRENCH_SCALES: [&str; 7] = [
+	"", "mille", "million", "milliard", "billion", "billiard", "trillion",
+];
+const FRENCH_MASCULINE_ORDINAL_SUFFIXES: &[&str] = &["er", "ᵉʳ"];
+const FRENCH_FEMININE_ORDINAL_SUFFIXES: &[&str] = &["re", "ʳᵉ", "ère"];
+const FRENCH_ORDINAL_SUFFIXES: &[&str] = &["e", "ᵉ", "ème", "eme", "è"];
+
+/// Longest decimal part read as a number in French, e.g. `3,14` → `trois virgule quatorze`.
+/// Longer decimal parts are read digit by digit.
+const FRENCH_MAX_DECIMAL_NUMBER_LENGTH: usize = 3;
+
+/// Language numbers are spelled out in.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Language {
+	/// English, e.g. `1,984.5` → `one thousand nine hundred eighty-four point five`.
+	#[default]
+	English,
+	/// French, e.g. `1984,5` → `mille neuf cent quatre-vingt-quatre virgule cinq`.
+	French,
+}
+
+impl fmt::Display for Language {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::English => "english",
+			Self::French => "french",
+		})
+	}
+}
+
+impl Language {
+	/// Names of all languages, as accepted by [`Language::from_name`].
+	pub const NAMES: &'static [&'static str] = &["english", "french"];
+
+	/// Gets a language by name.
+	///
+	/// Returns `None` for unknown names.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"english" => Some(Self::English),
+			"french" => Some(Self::French),
+			_ => None,
+		}
+	}
+
+	const fn decimal_separator(self) -> char {
+		match self {
+			Self::English => '.',
+			Self::French => ',',
+		}
+	}
+
+	/// Thousands separators. Spaces are only found by [`split_words`].
+	const fn group_separators(self) -> &'static [char] {
+		match self {
+			Self::English => &[','],
+			Self::French => &['.', ' ', '\u{a0}', '\u{202f}'],
+		}
+	}
+}
+
+/// Spells out a number written in digits.
+///
+/// Cardinals, ordinals and decimals are supported, with optional thousands separators. In
+/// English, four-digit numbers from 1100 to 1999 and from 2010 to 2099 are read as years.
+///
+/// Returns `None` if the word is not a number.
+///
+/// ```
+/// # use porcus::number::{verbalize, Language};
+/// let english = |word| verbalize(word, Language::English);
+/// assert_eq!(english("1,000,001").as_deref(), Some("one million one"));
+/// assert_eq!(english("21st").as_deref(), Some("twenty-first"));
+/// assert_eq!(english("1984").as_deref(), Some("nineteen eighty-four"));
+/// assert_eq!(english("3.14").as_deref(), Some("three point one four"));
+/// assert_eq!(english("007").as_deref(), Some("zero zero seven"));
+/// assert_eq!(english("42nd2"), None);
+///
+/// let french = |word| verbalize(word, Language::French);
+/// assert_eq!(french("80").as_deref(), Some("quatre-vingts"));
+/// assert_eq!(french("1re").as_deref(), Some("première"));
+/// assert_eq!(french("71e").as_deref(), Some("soixante et onzième"));
+/// assert_eq!(french("3,14").as_deref(), Some("trois virgule quatorze"));
+/// ```
+#[must_use]
+pub fn verbalize(word: &str, language: Language) -> Option<String> {
+	let decimal_separator = language.decimal_separator();
+	let group_separators = language.group_separators();
+	let number_end = word
+		.find(|c: char| {
+			!c.is_ascii_digit() && c != decimal_separator && !group_separators.contains(&c)
+		})
+		.unwrap_or(word.len());
+	let (number, suffix) = word.split_at(number_end);
+	if !number.ends_with(|c: char| c.is_ascii_digit()) {
+		return None;
+	}
+
+	let (integer, decimals) = match number.split_once(decimal_separator) {
+		Some((integer, decimals)) if decimals.chars().all(|c| c.is_ascii_digit()) => {
+			(integer, Some(decimals))
+		}
+		Some(_) => return None,
+		None => (number, None),
+	};
+	let is_grouped = integer.contains(group_separators);
+	let integer = ungroup(integer, group_separators)?;
+	if integer.len() > 1 && integer.starts_with('0') {
+		return if is_grouped || decimals.is_some() || !suffix.is_empty() {
+			None
+		} else {
+			Some(digits(&integer, language))
+		};
+	}
+	let value = integer.parse::<u64>().ok()?;
+
+	match (decimals, suffix) {
+		(Some(decimals), "") => Some(decimal(value, decimals, language)),
+		(Some(_), _) => None,
+		(None, "") if !is_grouped && language == Language::English => {
+			Some(english_year(value).unwrap_or_else(|| cardinal(value, language)))
+		}
+		(None, "") => Some(cardinal(value, language)),
+		(None, suffix) => ordinal_with_suffix(value, suffix, language),
+	}
+}
+
+/// Splits a string on word boundaries, keeping numbers with space-separated thousands together.
+///
+/// ```
+/// # use porcus::number::{split_words, Language};
+/// assert_eq!(
+///     split_words("80 000 ans", Language::French),
+///     vec!["80 000", " ", "ans"]
+/// );
+/// assert_eq!(
+///     split_words("80 000 ans", Language::English),
+///     vec!["80", " ", "000", " ", "ans"]
+/// );
+/// ```
+#[must_use]
+pub fn split_words(s: &str, language: Language) -> Vec<&str> {
+	let bounds = s.split_word_bound_indices().collect::<Vec<(usize, &str)>>();
+	let is_separator = |word: &str| {
+		let mut chars = word.chars();
+		chars
+			.next()
+			.is_some_and(|c| c.is_whitespace() && language.group_separators().contains(&c))
+			&& chars.next().is_none()
+	};
+	let leading_digits = |word: &str| word.bytes().take_while(u8::is_ascii_digit).count();
+
+	let mut words = Vec::new();
+	let mut index = 0;
+	while index < bounds.len() {
+		let (start, mut word) = bounds[index];
+		index += 1;
+		if (1..=3).contains(&word.len()) && leading_digits(word) == word.len() {
+			while let [(_, separator), (group_start, group), ..] = bounds[index..] {
+				if !is_separator(separator) || leading_digits(group) != 3 {
+					break;
+				}
+				word = &s[start..group_start + group.len()];
+				index += 2;
+				if group.len() > 3 {
+					break;
+				}
+			}
+		}
+		words.push(word);
+	}
+	words
+}
+
+/// Spells out a cardinal number.
+///
+/// ```
+/// # use porcus::number::{cardinal, Language};
+/// assert_eq!(cardinal(0, Language::English), "zero");
+/// assert_eq!(cardinal(2_023, Language::English), "two thousand twenty-three");
+/// assert_eq!(cardinal(21, Language::French), "vingt et un");
+/// assert_eq!(cardinal(200_080, Language::French), "deux cent mille quatre-vingts");
+/// ```
+#[must_use]
+pub fn cardinal(n: u64, language: Language) -> String {
+	match language {
+		Language::English => english_cardinal(n),
+		Language::French => french_cardinal(n),
+	}
+}
+
+/// Spells out an ordinal number, in the masculine for French.
+///
+/// ```
+/// # use porcus::number::{ordinal, Language};
+/// assert_eq!(ordinal(3, Language::English), "third");
+/// assert_eq!(ordinal(40, Language::English), "fortieth");
+/// assert_eq!(ordinal(1, Language::French), "premier");
+/// assert_eq!(ordinal(81, Language::French), "quatre-vingt-unième");
+/// ```
+#[must_use]
+pub fn ordinal(n: u64, language: Language) -> String {
+	let cardinal = cardinal(n, language);
+	let last_word_start = cardinal.rfind([' ', '-']).map_or(0, |index| index + 1);
+	let (start, last_word) = cardinal.split_at(last_word_start);
+	let last_word = match language {
+		Language::English => match last_word {
+			"one" => String::from("first"),
+			"two" => String::from("second"),
+			"three" => String::from("third"),
+			"five" => String::from("fifth"),
+			"eight" => String::from("eighth"),
+			"nine" => String::from("ninth"),
+			"twelve" => String::from("twelfth"),
+			word => match word.strip_suffix('y') {
+				Some(stem) => format!("{}ieth", stem),
+				None => format!("{}th", word),
+			},
+		},
+		Language::French if n == 1 => String::from("premier"),
+		Language::French => {
+			let stem = match last_word {
+				"cinq" => "cinqu",
+				"neuf" => "neuv",
+				"cents" | "vingts" => &last_word[..last_word.len() - 1],
+				word => word.strip_suffix('e').unwrap_or(word),
+			};
+			format!("{}ième", stem)
+		}
+	};
+	format!("{}{}", start, last_word)
+}
+
+fn ordinal_with_suffix(n: u64, suffix: &str, language: Language) -> Option<String> {
+	let suffix = suffix.to_lowercase();
+	let suffix = suffix.as_str();
+	match language {
+		Language::English if suffix == english_ordinal_suffix(n) => Some(ordinal(n, language)),
+		Language::French if n == 1 && FRENCH_MASCULINE_ORDINAL_SUFFIXES.contains(&suffix) => {
+			Some(ordinal(n, language))
+		}
+		Language::French if n == 1 && FRENCH_FEMININE_ORDINAL_SUFFIXES.contains(&suffix) => {
+			Some(String::from("première"))
+		}
+		Language::French if FRENCH_ORDINAL_SUFFIXES.contains(&suffix) => Some(ordinal(n, language)),
+		_ => None,
+	}
+}
+
+/// Gets the suffix of an English ordinal in digits, e.g. `st` for `21st` but `th` for `11th`.
+const fn english_ordinal_suffix(n: u64) -> &'static str {
+	match (n % 100, n % 10) {
+		(11..=13, _) => "th",
+		(_, 1) => "st",
+		(_, 2) => "nd",
+		(_, 3) => "rd",
+		_ => "th",
+	}
+}
+
+/// Removes thousands separators, checking that groups have three digits.
+fn ungroup(integer: &str, group_separators: &[char]) -> Option<String> {
+	let mut groups = integer.split(group_separators);
+	let first = groups.next()?;
+	let is_valid = !first.is_empty()
+		&& (first.len() <= 3 || !integer.contains(group_separators))
+		&& groups.all(|group| group.len() == 3);
+	is_valid.then(|| integer.replace(group_separators, ""))
+}
+
+fn digits(digits: &str, language: Language) -> String {
+	digits
+		.chars()
+		.filter_map(|c| c.to_digit(10))
+		.map(|digit| cardinal(u64::from(digit), language))
+		.collect::<Vec<String>>()
+		.join(" ")
+}
+
+fn decimal(integer: u64, decimals: &str, language: Language) -> String {
+	let integer = cardinal(integer, language);
+	match language {
+		Language::English => format!("{} point {}", integer, digits(decimals, language)),
+		Language::French if decimals.len() <= FRENCH_MAX_DECIMAL_NUMBER_LENGTH => {
+			let significant = decimals.trim_start_matches('0');
+			let zeros = digits(&decimals[..decimals.len() - significant.len()], language);
+			let significant = significant
+				.parse::<u64>()
+				.map(|value| cardinal(value, language))
+				.unwrap_or_default();
+			let decimals = [zeros, significant]
+				.into_iter()
+				.filter(|part| !part.is_empty())
+				.collect::<Vec<String>>()
+				.join(" ");
+			format!("{} virgule {}", integer, decimals)
+		}
+		Language::French => format!("{} virgule {}", integer, digits(decimals, language)),
+	}
+}
+
+/// Splits a number into groups of three digits, from the lowest.
+fn thousands(mut n: u64) -> Vec<u64> {
+	let mut groups = Vec::new();
+	while n > 0 {
+		groups.push(n % 1000);
+		n /= 1000;
+	}
+	groups
+}
+
+fn english_cardinal(n: u64) -> String {
+	if n == 0 {
+		return String::from(ENGLISH_UNITS[0]);
+	}
+	let mut words = Vec::new();
+	for (scale, group) in thousands(n).into_iter().enumerate().rev() {
+		if group > 0 {
+			words.push(english_below_thousand(group));
+			if scale > 0 {
+				words.push(String::from(ENGLISH_SCALES[scale]));
+			}
+		}
+	}
+	words.join(" ")
+}
+
+fn english_below_hundred(n: u64) -> String {
+	match (n / 10, n % 10) {
+		(0 | 1, _) => String::from(ENGLISH_UNITS[n as usize]),
+		(tens, 0) => String::from(ENGLISH_TENS[tens as usize]),
+		(tens, units) => format!(
+			"{}-{}",
+			ENGLISH_TENS[tens as usize], ENGLISH_UNITS[units as usize]
+		),
+	}
+}
+
+fn english_below_thousand(n: u64) -> String {
+	match (n / 100, n % 100) {
+		(0, rest) => english_below_hundred(rest),
+		(hundreds, 0) => format!("{} hundred", ENGLISH_UNITS[hundreds as usize]),
+		(hundreds, rest) => format!(
+			"{} hundred {}",
+			ENGLISH_UNITS[hundreds as usize],
+			english_below_hundred(rest)
+		),
+	}
+}
+
+/// Reads a number as a year, e.g. `1984` → `nineteen eighty-four`.
+fn english_year(n: u64) -> Option<String> {
+	if !matches!(n, 1100..=1999 | 2010..=2099) {
+		return None;
+	}
+	let century = english_below_hundred(n / 100);
+	Some(match n % 100 {
+		0 => format!("{} hundred", century),
+		rest @ 1..=9 => format!("{} oh {}", century, ENGLISH_UNITS[rest as usize]),
+		rest => format!("{} {}", century, english_below_hundred(rest)),
+	})
+}
+
+fn french_cardinal(n: u64) -> String {
+	if n == 0 {
+		return String::from(FRENCH_UNITS[0]);
+	}
+	let mut words = Vec::new();
+	for (scale, group) in thousands(n).into_iter().enumerate().rev() {
+		match (scale, group) {
+			(_, 0) => {}
+			(0, group) => words.push(french_below_thousand(group, true)),
+			(1, 1) => words.push(String::from(FRENCH_SCALES[1])),
+			(1, group) => {
+				words.push(french_below_thousand(group, false));
+				words.push(String::from(FRENCH_SCALES[1]));
+			}
+			(scale, group) => {
+				words.push(french_below_thousand(group, true));
+				let plural = if group > 1 { "s" } else { "" };
+				words.push(format!("{}{}", FRENCH_SCALES[scale], plural));
+			}
+		}
+	}
+	words.join(" ")
+}
+
+/// Spells out a number below a thousand. `cent` and `vingt` only take a plural if the number is
+/// not followed by `mille`.
+fn french_below_thousand(n: u64, may_be_plural: bool) -> String {
+	let rest = n % 100;
+	let hundreds = match n / 100 {
+		0 => None,
+		1 => Some(String::from("cent")),
+		count => {
+			let plural = if rest == 0 && may_be_plural { "s" } else { "" };
+			Some(format!("{} cent{}", FRENCH_UNITS[count as usize], plural))
+		}
+	};
+	let rest = (rest > 0 || hundreds.is_none()).then(|| french_below_hundred(rest, may_be_plural));
+	[hundreds, rest]
+		.into_iter()
+		.flatten()
+		.collect::<Vec<String>>()
+		.join(" ")
+}
+
+fn french_below_hundred(n: u64, may_be_plural: bool) -> String {
+	let tens = (n / 10) as usize;
+	let units = (n % 10) as usize;
+	match n {
+		0..=19 => String::from(FRENCH_UNITS[n as usize]),
+		20..=69 => match units {
+			0 => String::from(FRENCH_TENS[tens]),
+			1 => format!("{} et un", FRENCH_TENS[tens]),
+			_ => format!("{}-{}", FRENCH_TENS[tens], FRENCH_UNITS[units]),
+		},
+		71 => String::from("soixante et onze"),
+		70..=79 => format!("soixante-{}", FRENCH_UNITS[units + 10]),
+		80 if may_be_plural => String::from("quatre-vingts"),
+		80 => String::from("quatre-vingt"),
+		_ => format!("quatre-vingt-{}", FRENCH_UNITS[(n - 80) as usize]),
+	}
+}
+
+#[cfg(test)]
+mod test_english {
+	use super::*;
+
+	fn assert_english(word: &str, expected: &str) {
+		assert_eq!(
+			verbalize(word, Language::English).as_deref(),
+			Some(expected),
+			"{}",
+			word
+		);
+	}
+
+	#[test]
+	fn cardinals() {
+		assert_english("0", "zero");
+		assert_english("13", "thirteen");
+		assert_english("90", "ninety");
+		assert_english("101", "one hundred one");
+		assert_english("2000", "two thousand");
+		assert_english("2005", "two thousand five");
+		assert_english("1,000", "one thousand");
+		assert_english("12,345", "twelve thousand three hundred forty-five");
+		assert_english(
+			"18446744073709551615",
+			"eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four \
+			 trillion seventy-three billion seven hundred nine million five hundred fifty-one \
+			 thousand six hundred fifteen",
+		);
+	}
+
+	#[test]
+	fn years() {
+		assert_english("1100", "eleven hundred");
+		assert_english("1905", "nineteen oh five");
+		assert_english("2024", "twenty twenty-four");
+		assert_english("1,984", "one thousand nine hundred eighty-four");
+		assert_english("2100", "two thousand one hundred");
+	}
+
+	#[test]
+	fn ordinals() {
+		assert_english("1st", "first");
+		assert_english("2ND", "second");
+		assert_english("11th", "eleventh");
+		assert_english("12th", "twelfth");
+		assert_english("20th", "twentieth");
+		assert_english("100th", "one hundredth");
+		assert_english("1,000,000th", "one millionth");
+		assert_english("13th", "thirteenth");
+		assert_english("112th", "one hundred twelfth");
+		assert_english("23rd", "twenty-third");
+	}
+
+	#[test]
+	fn mismatched_ordinal_suffixes() {
+		for word in ["2st", "1nd", "3th", "11st", "12nd", "13rd", "4rd", "22th"] {
+			assert_eq!(verbalize(word, Language::English), None, "{}", word);
+		}
+	}
+
+	#[test]
+	fn decimals() {
+		assert_english("0.5", "zero point five");
+		assert_english("1,000.25", "one thousand point two five");
+	}
+
+	#[test]
+	fn not_numbers() {
+		for word in [
+			"", "abc", "1.", ".5", "1,00", "1,0000", "1.2.3", "42µm", "3.5th", "007th",
+		] {
+			assert_eq!(verbalize(word, Language::English), None, "{}", word);
+		}
+		assert_eq!(verbalize("18446744073709551616", Language::English), None);
+	}
+}
+
+#[cfg(test)]
+mod test_french {
+	use super::*;
+
+	fn assert_french(word: &str, expected: &str) {
+		assert_eq!(
+			verbalize(word, Language::French).as_deref(),
+			Some(expected),
+			"{}",
+			word
+		);
+	}
+
+	#[test]
+	fn cardinals() {
+		assert_french("17", "dix-sept");
+		assert_french("21", "vingt et un");
+		assert_french("70", "soixante-dix");
+		assert_french("77", "soixante-dix-sept");
+		assert_french("81", "quatre-vingt-un");
+		assert_french("91", "quatre-vingt-onze");
+		assert_french("100", "cent");
+		assert_french("101", "cent un");
+		assert_french("200", "deux cents");
+		assert_french("280", "deux cent quatre-vingts");
+		assert_french("1000", "mille");
+		assert_french("1984", "mille neuf cent quatre-vingt-quatre");
+		assert_french("80000", "quatre-vingt mille");
+		assert_french("200000", "deux cent mille");
+		assert_french("1.000.000", "un million");
+		assert_french("80000000", "quatre-vingts millions");
+		assert_french("2000000000", "deux milliards");
+	}
+
+	#[test]
+	fn ordinals() {
+		assert_french("1er", "premier");
+		assert_french("1ʳᵉ", "première");
+		assert_french("2e", "deuxième");
+		assert_french("5ème", "cinquième");
+		assert_french("9ᵉ", "neuvième");
+		assert_french("21e", "vingt et unième");
+		assert_french("80e", "quatre-vingtième");
+		assert_french("200e", "deux centième");
+		assert_french("1000e", "millième");
+	}
+
+	#[test]
+	fn space_separators() {
+		assert_french("1 000 000", "un million");
+		assert_french("80\u{202f}000,5", "quatre-vingt mille virgule cinq");
+		let words = split_words("1 000 000e, 12  345, 1234 567, 1 0000", Language::French);
+		assert_eq!(
+			words,
+			vec![
+				"1 000 000e",
+				",",
+				" ",
+				"12",
+				"  ",
+				"345",
+				",",
+				" ",
+				"1234",
+				" ",
+				"567",
+				",",
+				" ",
+				"1",
+				" ",
+				"0000"
+			]
+		);
+	}
+
+	#[test]
+	fn decimals() {
+		assert_french("0,5", "zéro virgule cinq");
+		assert_french("3,05", "trois virgule zéro cinq");
+		assert_french("2,000", "deux virgule zéro zéro zéro");
+		assert_french("1,2345", "un virgule deux trois quatre cinq");
+	}
+
+	#[test]
+	fn not_numbers() {
+		for word in ["2er", "2re", "1st", "3.14e"] {
+			assert_eq!(verbalize(word, Language::French), None, "{}", word);
+		}
+	}
+}
@@ -0,0 +1,347 @@
+//! Spelling conventions of specific languages.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::orthography::Orthography;
+//! use porcus::PigLatinTransformer;
+//!
+//! let transformer = PigLatinTransformer::default().with_orthography(Orthography::English);
+//! assert_eq!(transformer.to_pig_latin("quiet"), "ietquay");
+//! ```
+
+use crate::char_type::CharType;
+use crate::latin::{
+	CZECH_MULTIGRAPHS, CZECH_MULTIGRAPH_LENGTH, CZECH_ONSETS, DUTCH_MULTIGRAPHS,
+	DUTCH_MULTIGRAPH_LENGTH, DUTCH_ONSETS, ENGLISH_LETTER_NAMES, ENGLISH_MULTIGRAPHS,
+	ENGLISH_MULTIGRAPH_LENGTH, ENGLISH_ONSETS, FRENCH_LETTER_NAMES, FRENCH_MULTIGRAPHS,
+	FRENCH_MULTIGRAPH_LENGTH, FRENCH_ONSETS, GENERIC_ONSETS, GERMAN_MULTIGRAPHS,
+	GERMAN_MULTIGRAPH_LENGTH, GERMAN_ONSETS, SPANISH_MULTIGRAPHS, SPANISH_MULTIGRAPH_LENGTH,
+	SPANISH_ONSETS, WELSH_MULTIGRAPHS, WELSH_MULTIGRAPH_LENGTH, WELSH_ONSETS,
+};
+use alloc::string::String;
+use core::fmt;
+use unicode_normalization::UnicodeNormalization;
+
+/// Sequence of letters classified as a single unit, e.g. `qu` in English or `ll` in Welsh.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Multigraph {
+	/// Classification of the whole sequence.
+	pub char_type: CharType,
+	/// Letters which must follow the sequence for it to match, e.g. `e` and `i` for French `gu`.
+	///
+	/// Accents are ignored. An empty list matches any context.
+	pub before: &'static [char],
+}
+
+impl Multigraph {
+	/// Sequence matching in any context.
+	#[must_use]
+	pub const fn new(char_type: CharType) -> Self {
+		Self {
+			char_type,
+			before: &[],
+		}
+	}
+
+	/// Sequence matching only when followed by one of the listed letters.
+	#[must_use]
+	pub const fn before(char_type: CharType, before: &'static [char]) -> Self {
+		Self { char_type, before }
+	}
+}
+
+/// Spelling conventions of a language.
+///
+/// Each orthography has a table of [multigraphs](Multigraph) which are moved as a single unit.
+///
+/// # Examples
+///
+/// The generic orthography has no multigraphs.
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// let transformer = PigLatinTransformer::default();
+/// assert_eq!(transformer.to_pig_latin("quiet"), "uietqay");
+/// ```
+///
+/// Multigraphs may be consonants or vowels.
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::orthography::Orthography;
+/// let transformer = PigLatinTransformer::default().with_orthography(Orthography::Dutch);
+/// assert_eq!(transformer.to_pig_latin("ijs"), "ijsway");
+///
+/// let transformer = PigLatinTransformer::default().with_orthography(Orthography::Welsh);
+/// assert_eq!(transformer.to_pig_latin("wyth"), "wythway");
+/// ```
+///
+/// Some multigraphs only match before specific letters.
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::orthography::Orthography;
+/// let transformer = PigLatinTransformer::default().with_orthography(Orthography::French);
+/// assert_eq!(transformer.to_pig_latin("guerre"), "erreguay");
+/// assert_eq!(transformer.to_pig_latin("gare"), "aregay");
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Orthography {
+	/// No language-specific rules.
+	#[default]
+	Generic,
+	/// Czech, e.g. `ch`.
+	Czech,
+	/// Dutch, e.g. `ij`.
+	Dutch,
+	/// English, e.g. `qu`, `th`.
+	English,
+	/// French, e.g. `qu`, `gu` before `e` or `i`.
+	French,
+	/// German, e.g. `sch`.
+	German,
+	/// Spanish, e.g. `ll`, `gu` before `e` or `i`.
+	Spanish,
+	/// Welsh, e.g. `ll`, `w` as a vowel.
+	Welsh,
+}
+
+impl fmt::Display for Orthography {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Generic => "generic",
+			Self::Czech => "czech",
+			Self::Dutch => "dutch",
+			Self::English => "english",
+			Self::French => "french",
+			Self::German => "german",
+			Self::Spanish => "spanish",
+			Self::Welsh => "welsh",
+		})
+	}
+}
+
+impl Orthography {
+	/// Names of all orthographies, as accepted by [`Orthography::from_name`].
+	pub const NAMES: &'static [&'static str] = &[
+		"generic", "czech", "dutch", "english", "french", "german", "spanish", "welsh",
+	];
+
+	/// Gets an orthography by name.
+	///
+	/// Returns `None` for unknown names.
+	///
+	/// ```
+	/// # use porcus::orthography::Orthography;
+	/// assert_eq!(Orthography::from_name("welsh"), Some(Orthography::Welsh));
+	/// assert_eq!(Orthography::from_name("klingon"), None);
+	/// ```
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"generic" => Some(Self::Generic),
+			"czech" => Some(Self::Czech),
+			"dutch" => Some(Self::Dutch),
+			"english" => Some(Self::English),
+			"french" => Some(Self::French),
+			"german" => Some(Self::German),
+			"spanish" => Some(Self::Spanish),
+			"welsh" => Some(Self::Welsh),
+			_ => None,
+		}
+	}
+
+	/// Gets the table of multigraphs, keyed by their lowercase spelling.
+	#[must_use]
+	pub fn multigraphs(self) -> Option<&'static phf::Map<&'static str, Multigraph>> {
+		self.multigraph_table().map(|(multigraphs, _)| multigraphs)
+	}
+
+	/// Gets the table of multigraphs and the length in letters of its longest key.
+	fn multigraph_table(self) -> Option<(&'static phf::Map<&'static str, Multigraph>, usize)> {
+		match self {
+			Self::Generic => None,
+			Self::Czech => Some((&CZECH_MULTIGRAPHS, CZECH_MULTIGRAPH_LENGTH)),
+			Self::Dutch => Some((&DUTCH_MULTIGRAPHS, DUTCH_MULTIGRAPH_LENGTH)),
+			Self::English => Some((&ENGLISH_MULTIGRAPHS, ENGLISH_MULTIGRAPH_LENGTH)),
+			Self::French => Some((&FRENCH_MULTIGRAPHS, FRENCH_MULTIGRAPH_LENGTH)),
+			Self::German => Some((&GERMAN_MULTIGRAPHS, GERMAN_MULTIGRAPH_LENGTH)),
+			Self::Spanish => Some((&SPANISH_MULTIGRAPHS, SPANISH_MULTIGRAPH_LENGTH)),
+			Self::Welsh => Some((&WELSH_MULTIGRAPHS, WELSH_MULTIGRAPH_LENGTH)),
+		}
+	}
+
+	/// Gets the set of consonant clusters which may begin a syllable, in lowercase.
+	///
+	/// Single consonants are not listed, as they are always allowed.
+	#[must_use]
+	pub fn onsets(self) -> &'static phf::Set<&'static str> {
+		match self {
+			Self::Generic => &GENERIC_ONSETS,
+			Self::Czech => &CZECH_ONSETS,
+			Self::Dutch => &DUTCH_ONSETS,
+			Self::English => &ENGLISH_ONSETS,
+			Self::French => &FRENCH_ONSETS,
+			Self::German => &GERMAN_ONSETS,
+			Self::Spanish => &SPANISH_ONSETS,
+			Self::Welsh => &WELSH_ONSETS,
+		}
+	}
+
+	/// Gets the names of the basic Latin letters, keyed by lowercase letter.
+	///
+	/// Names are in French for [`Orthography::French`], and in English otherwise.
+	///
+	/// ```
+	/// # use porcus::orthography::Orthography;
+	/// assert_eq!(Orthography::English.letter_names().get(&'h'), Some(&"aitch"));
+	/// assert_eq!(Orthography::French.letter_names().get(&'h'), Some(&"ache"));
+	/// ```
+	#[must_use]
+	pub fn letter_names(self) -> &'static phf::Map<char, &'static str> {
+		match self {
+			Self::French => &FRENCH_LETTER_NAMES,
+			_ => &ENGLISH_LETTER_NAMES,
+		}
+	}
+
+	/// Finds the longest multigraph starting at the specified grapheme index.
+	///
+	/// Returns the number of graphemes matched and the multigraph, or `None` if no multigraph
+	/// matches.
+	///
+	/// ```
+	/// # use porcus::orthography::Orthography;
+	/// # use porcus::char_type::CharType;
+	/// let graphemes = &["S", "c", "h", "a", "f"];
+	/// let (length, multigraph) = Orthography::German.multigraph_at(graphemes, 0).unwrap();
+	/// assert_eq!(length, 3);
+	/// assert_eq!(multigraph.char_type, CharType::Consonant);
+	/// ```
+	#[must_use]
+	pub fn multigraph_at(self, graphemes: &[&str], index: usize) -> Option<(usize, Multigraph)> {
+		let (multigraphs, max_length) = self.multigraph_table()?;
+		let window = graphemes.get(index..)?;
+		let window = &window[..window.len().min(max_length)];
+
+		// Candidates grow one grapheme at a time, so the longest match is the last one found.
+		let mut candidate = String::new();
+		let mut longest = None;
+		for (length, grapheme) in (1..).zip(window) {
+			candidate.extend(grapheme.chars().flat_map(char::to_lowercase).nfc());
+			if let Some(multigraph) = multigraphs
+				.get(candidate.as_str())
+				.filter(|multigraph| is_followed_by(graphemes, index + length, multigraph.before))
+			{
+				longest = Some((length, *multigraph));
+			}
+		}
+		longest
+	}
+}
+
+fn is_followed_by(graphemes: &[&str], index: usize, letters: &[char]) -> bool {
+	if letters.is_empty() {
+		return true;
+	}
+	graphemes
+		.get(index)
+		.and_then(|grapheme| grapheme.nfd().next())
+		.is_some_and(|c| c.to_lowercase().any(|lower| letters.contains(&lower)))
+}
+
+#[cfg(test)]
+mod test_names {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		for name in Orthography::NAMES {
+			let orthography = Orthography::from_name(name).unwrap();
+			assert_eq!(orthography.to_string(), *name);
+		}
+	}
+}
+
+#[cfg(test)]
+mod test_multigraph_at {
+	use super::*;
+
+	#[test]
+	fn generic_has_none() {
+		assert_eq!(Orthography::Generic.multigraph_at(&["q", "u"], 0), None);
+	}
+
+	#[test]
+	fn greedy() {
+		let graphemes = &["s", "c", "h", "a"];
+		assert_eq!(
+			Orthography::German.multigraph_at(graphemes, 0).map(|m| m.0),
+			Some(3)
+		);
+		assert_eq!(
+			Orthography::German.multigraph_at(graphemes, 1).map(|m| m.0),
+			Some(2)
+		);
+	}
+
+	#[test]
+	fn case_insensitive() {
+		let graphemes = &["I", "J", "s"];
+		let (length, multigraph) = Orthography::Dutch.multigraph_at(graphemes, 0).unwrap();
+		assert_eq!(length, 2);
+		assert_eq!(multigraph.char_type, CharType::Vowel);
+	}
+
+	#[test]
+	fn context() {
+		assert!(Orthography::Spanish
+			.multigraph_at(&["g", "u", "é"], 0)
+			.is_some());
+		assert!(Orthography::Spanish
+			.multigraph_at(&["g", "u", "a"], 0)
+			.is_none());
+		assert!(Orthography::Spanish
+			.multigraph_at(&["g", "ü", "e"], 0)
+			.is_none());
+		assert!(Orthography::Spanish.multigraph_at(&["g", "u"], 0).is_none());
+	}
+
+	#[test]
+	fn normalization_insensitive() {
+		assert!(Orthography::Czech.multigraph_at(&["D", "ž"], 0).is_some());
+		assert!(Orthography::Czech
+			.multigraph_at(&["d", "z\u{30c}"], 0)
+			.is_some());
+	}
+
+	#[test]
+	fn english_gu() {
+		assert!(Orthography::English
+			.multigraph_at(&["g", "u", "e"], 0)
+			.is_some());
+		assert!(Orthography::English
+			.multigraph_at(&["g", "u", "a"], 0)
+			.is_none());
+	}
+
+	#[test]
+	fn max_lengths() {
+		for name in Orthography::NAMES {
+			let orthography = Orthography::from_name(name).unwrap();
+			if let Some((multigraphs, max_length)) = orthography.multigraph_table() {
+				let longest = multigraphs.keys().map(|key| key.chars().count()).max();
+				assert_eq!(longest, Some(max_length), "{name}");
+			}
+		}
+	}
+
+	#[test]
+	fn out_of_bounds() {
+		assert_eq!(Orthography::English.multigraph_at(&["q"], 0), None);
+		assert_eq!(Orthography::English.multigraph_at(&["q", "u"], 5), None);
+	}
+}
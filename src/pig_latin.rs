@@ -1,7 +1,29 @@
 use super::{DEFAULT_CONSONANT_SUFFIX, DEFAULT_VOWEL_SUFFIX};
-use crate::case;
+use crate::acronym::{self, AcronymPolicy};
+use crate::ascii;
+#[cfg(feature = "std")]
+use crate::cache::WordCache;
+use crate::case::{Case, Locale};
 use crate::char_type::{self, CharType};
-use std::fmt;
+use crate::compound::{self, Contraction, Elision, Hyphenation};
+use crate::dialect::{Dialect, Onset, YRule};
+use crate::encoding;
+use crate::exceptions::Exceptions;
+use crate::lexicon::Lexicon;
+use crate::normalization::Normalization;
+use crate::number::{self, Language};
+use crate::orthography::Orthography;
+use crate::style;
+use crate::token::{self, TokenKind, TokenPolicy};
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt;
+use core::ops::Range;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use unicode_normalization::UnicodeNormalization;
 use unicode_script::UnicodeScript;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -13,9 +35,9 @@ use unicode_segmentation::UnicodeSegmentation;
 /// within a text.
 ///
 /// - If a word starts with a consonant, all initial consonants are moved to the end of the word,
-/// then the suffix "ay" is appended, e.g. `nix` becoomes `ixnay` and `scram` becomes `amscray`.
+///   then the suffix "ay" is appended, e.g. `nix` becoomes `ixnay` and `scram` becomes `amscray`.
 /// - If it starts with a vowel, the suffix "way" is appended to it, e.g. `eat` becomes `eatway`.
-/// There are many variants of pig latin using different vowel suffixes, such as "yay" or "hay".
+///   There are many variants of pig latin using different vowel suffixes, such as "yay" or "hay".
 ///
 /// # Examples
 ///
@@ -51,6 +73,14 @@ use unicode_segmentation::UnicodeSegmentation;
 /// assert_eq!(transformer.to_pig_latin("pɪɡ lætɪn"), "ɪɡpay ætɪnlay");
 /// ```
 ///
+/// [Styled letters](crate::style) keep their style, which is also applied to the suffix.
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # let transformer = PigLatinTransformer::default();
+/// assert_eq!(transformer.to_pig_latin("𝐇𝐞𝐥𝐥𝐨"), "𝐄𝐥𝐥𝐨𝐡𝐚𝐲");
+/// ```
+///
 /// Only words starting with Latin characters are transformed.
 ///
 /// ```
@@ -71,18 +101,130 @@ use unicode_segmentation::UnicodeSegmentation;
 /// let pig_latin = transformer.to_pig_latin("Hi all!");
 /// assert_eq!(pig_latin, "Ih-ay all-yay!");
 /// ```
+///
+/// Other rule variants can be selected with a [`Dialect`].
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::dialect::Dialect;
+/// let dialect = Dialect::from_preset("first-consonant").unwrap();
+/// let transformer = PigLatinTransformer::default().with_dialect(dialect);
+/// assert_eq!(transformer.to_pig_latin("Scram!"), "Cramsay!");
+/// ```
+///
+/// Letter sequences which spell a single sound are moved as a unit according to the spelling
+/// conventions of an [`Orthography`].
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::orthography::Orthography;
+/// let transformer = PigLatinTransformer::default().with_orthography(Orthography::Spanish);
+/// assert_eq!(transformer.to_pig_latin("guitarra"), "itarraguay");
+/// ```
+///
+/// A pronunciation [`Lexicon`] decides whether a word starts with a consonant sound. Words whose
+/// initial consonant sound has no consonant letter are respelled from their pronunciation. Words
+/// missing from the lexicon follow the spelling.
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::lexicon::Lexicon;
+/// # #[cfg(feature = "std")] {
+/// let lexicon = Lexicon::from_cmudict("HOUR  AW1 ER0\nONE  W AH1 N\n".as_bytes()).unwrap();
+/// let transformer = PigLatinTransformer::default().with_lexicon(lexicon);
+/// assert_eq!(transformer.to_pig_latin("one hour hand"), "unway hourway andhay");
+/// # }
+/// ```
+///
+/// Acronyms can be kept or spelled out, see [`AcronymPolicy`].
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::acronym::AcronymPolicy;
+/// let transformer = PigLatinTransformer::default().with_acronym_policy(AcronymPolicy::Keep);
+/// assert_eq!(transformer.to_pig_latin("The BBC"), "Ethay BBC");
+/// ```
+///
+/// Case is matched with the rules of a [`Locale`].
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::case::Locale;
+/// let transformer = PigLatinTransformer::default().with_locale(Locale::Dutch);
+/// assert_eq!(transformer.to_pig_latin("Kijk"), "IJkkay");
+/// ```
+///
+/// Numbers can be spelled out before being translated.
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::number::Language;
+/// let transformer = PigLatinTransformer::default().with_numbers(Language::French);
+/// assert_eq!(transformer.to_pig_latin("21 ans"), "ingtvay etway unway answay");
+/// ```
+///
+/// [`Exceptions`] fix the translation of chosen words, matching their case.
+///
+/// ```
+/// # use porcus::PigLatinTransformer;
+/// # use porcus::exceptions::Exceptions;
+/// let exceptions = Exceptions::new().with_kept("iPhone").with_replacement("pig", "swine");
+/// let transformer = PigLatinTransformer::default().with_exceptions(exceptions);
+/// assert_eq!(transformer.to_pig_latin("Pig iPhone"), "Swine iPhone");
+/// ```
+///
+/// With the `serde` feature, transformers can be serialized, e.g. to load the translation
+/// settings of a `porcus.toml` profile. Missing settings take their default value and unknown
+/// settings are rejected, including the command-line settings `lexicon`, `ipa`, `format` and
+/// `encoding`. The lexicon is not serialized.
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// # use porcus::PigLatinTransformer;
+/// let transformer: PigLatinTransformer = toml::from_str(
+///     r#"
+///         vowel-suffix = "yay"
+///         dialect = "qu-cluster"
+///     "#,
+/// )
+/// .unwrap();
+/// assert_eq!(transformer.to_pig_latin("queen of egg"), "eenquay ofyay eggyay");
+/// # }
+/// ```
 #[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(default, deny_unknown_fields, rename_all = "kebab-case")
+)]
 pub struct PigLatinTransformer {
 	consonant_suffix: String,
 	vowel_suffix: String,
+	orthography: Orthography,
+	acronym_policy: AcronymPolicy,
+	token_policy: TokenPolicy,
+	normalization: Normalization,
+	locale: Locale,
+	sentence_capitalization: bool,
+	hyphenation: Hyphenation,
+	elision: Elision,
+	contraction: Contraction,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+	numbers: Option<Language>,
+	dialect: Dialect,
+	#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Exceptions::is_empty"))]
+	exceptions: Exceptions,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	lexicon: Option<Arc<Lexicon>>,
 }
 
 impl fmt::Display for PigLatinTransformer {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(
 			f,
-			"Pig Latin <C+{} V+{}>",
-			self.consonant_suffix, self.vowel_suffix
+			"Pig Latin <C+{} V+{}, {}>",
+			self.consonant_suffix, self.vowel_suffix, self.dialect
 		)
 	}
 }
@@ -92,6 +234,19 @@ impl Default for PigLatinTransformer {
 		Self {
 			consonant_suffix: String::from(DEFAULT_CONSONANT_SUFFIX),
 			vowel_suffix: String::from(DEFAULT_VOWEL_SUFFIX),
+			dialect: Dialect::default(),
+			orthography: Orthography::default(),
+			acronym_policy: AcronymPolicy::Transform,
+			token_policy: TokenPolicy::Split,
+			normalization: Normalization::None,
+			locale: Locale::Root,
+			sentence_capitalization: false,
+			hyphenation: Hyphenation::PerPart,
+			elision: Elision::Rotate,
+			contraction: Contraction::Whole,
+			numbers: None,
+			exceptions: Exceptions::new(),
+			lexicon: None,
 		}
 	}
 }
@@ -105,9 +260,135 @@ impl PigLatinTransformer {
 		Self {
 			consonant_suffix: consonant_suffix.into(),
 			vowel_suffix: vowel_suffix.into(),
+			dialect: Dialect::default(),
+			orthography: Orthography::default(),
+			acronym_policy: AcronymPolicy::Transform,
+			token_policy: TokenPolicy::Split,
+			normalization: Normalization::None,
+			locale: Locale::Root,
+			sentence_capitalization: false,
+			hyphenation: Hyphenation::PerPart,
+			elision: Elision::Rotate,
+			contraction: Contraction::Whole,
+			numbers: None,
+			exceptions: Exceptions::new(),
+			lexicon: None,
 		}
 	}
 
+	/// Sets the suffix appended to words starting with a consonant.
+	#[must_use]
+	pub fn with_consonant_suffix<S: Into<String>>(mut self, consonant_suffix: S) -> Self {
+		self.consonant_suffix = consonant_suffix.into();
+		self
+	}
+
+	/// Sets the suffix appended to words starting with a vowel.
+	#[must_use]
+	pub fn with_vowel_suffix<S: Into<String>>(mut self, vowel_suffix: S) -> Self {
+		self.vowel_suffix = vowel_suffix.into();
+		self
+	}
+
+	/// Sets the rule variant used to rotate words.
+	#[must_use]
+	pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+		self.dialect = dialect;
+		self
+	}
+
+	/// Sets the spelling conventions used to find letters moved as a unit.
+	#[must_use]
+	pub const fn with_orthography(mut self, orthography: Orthography) -> Self {
+		self.orthography = orthography;
+		self
+	}
+
+	/// Sets the pronunciation dictionary used to find consonant sounds.
+	///
+	/// The lexicon is shared between clones of the transformer.
+	#[must_use]
+	pub fn with_lexicon<L: Into<Arc<Lexicon>>>(mut self, lexicon: L) -> Self {
+		self.lexicon = Some(lexicon.into());
+		self
+	}
+
+	/// Sets how acronyms and initialisms are translated.
+	#[must_use]
+	pub const fn with_acronym_policy(mut self, acronym_policy: AcronymPolicy) -> Self {
+		self.acronym_policy = acronym_policy;
+		self
+	}
+
+	/// Sets how URLs, emails, paths, handles, hashtags, shortcodes and versions are translated.
+	#[must_use]
+	pub const fn with_token_policy(mut self, token_policy: TokenPolicy) -> Self {
+		self.token_policy = token_policy;
+		self
+	}
+
+	/// Sets the Unicode normalization form of the output.
+	#[must_use]
+	pub const fn with_normalization(mut self, normalization: Normalization) -> Self {
+		self.normalization = normalization;
+		self
+	}
+
+	/// Sets the language rules used to match the case of translated words.
+	#[must_use]
+	pub const fn with_locale(mut self, locale: Locale) -> Self {
+		self.locale = locale;
+		self
+	}
+
+	/// Sets whether capitals are repaired across sentences.
+	///
+	/// Each sentence then starts with a capital exactly when the input sentence did, and
+	/// capitalized words within sentences keep their initial capital.
+	#[must_use]
+	pub const fn with_sentence_capitalization(mut self, sentence_capitalization: bool) -> Self {
+		self.sentence_capitalization = sentence_capitalization;
+		self
+	}
+
+	/// Sets how hyphenated compounds are translated.
+	#[must_use]
+	pub const fn with_hyphenation(mut self, hyphenation: Hyphenation) -> Self {
+		self.hyphenation = hyphenation;
+		self
+	}
+
+	/// Sets how elided words, e.g. `l'` in `l'eau`, are translated.
+	#[must_use]
+	pub const fn with_elision(mut self, elision: Elision) -> Self {
+		self.elision = elision;
+		self
+	}
+
+	/// Sets how English contractions, e.g. `don't`, are translated.
+	#[must_use]
+	pub const fn with_contraction(mut self, contraction: Contraction) -> Self {
+		self.contraction = contraction;
+		self
+	}
+
+	/// Sets the language numbers are spelled out in before being translated.
+	#[must_use]
+	pub const fn with_numbers(mut self, language: Language) -> Self {
+		self.numbers = Some(language);
+		self
+	}
+
+	/// Sets the words with a fixed translation.
+	///
+	/// Only words starting with a letter are looked up, so exceptions for numbers or punctuation
+	/// have no effect.
+	#[must_use]
+	pub fn with_exceptions(mut self, exceptions: Exceptions) -> Self {
+		self.exceptions = exceptions;
+		self
+	}
+
 	/// Gets the suffix appended to words starting with a consonant.
 	#[must_use]
 	pub const fn consonant_suffix(&self) -> &String {
@@ -118,62 +399,562 @@ impl PigLatinTransformer {
 	pub const fn vowel_suffix(&self) -> &String {
 		&self.vowel_suffix
 	}
+	/// Gets the rule variant used to rotate words.
+	#[must_use]
+	pub const fn dialect(&self) -> &Dialect {
+		&self.dialect
+	}
+	/// Gets the spelling conventions used to find letters moved as a unit.
+	#[must_use]
+	pub const fn orthography(&self) -> Orthography {
+		self.orthography
+	}
+	/// Gets the pronunciation dictionary used to find consonant sounds, if any.
+	#[must_use]
+	pub fn lexicon(&self) -> Option<&Lexicon> {
+		self.lexicon.as_deref()
+	}
+	/// Gets how acronyms and initialisms are translated.
+	#[must_use]
+	pub const fn acronym_policy(&self) -> AcronymPolicy {
+		self.acronym_policy
+	}
+	/// Gets how URLs, emails, paths, handles, hashtags, shortcodes and versions are translated.
+	#[must_use]
+	pub const fn token_policy(&self) -> TokenPolicy {
+		self.token_policy
+	}
+	/// Gets the Unicode normalization form of the output.
+	#[must_use]
+	pub const fn normalization(&self) -> Normalization {
+		self.normalization
+	}
+	/// Gets the language rules used to match the case of translated words.
+	#[must_use]
+	pub const fn locale(&self) -> Locale {
+		self.locale
+	}
+	/// Gets whether capitals are repaired across sentences.
+	#[must_use]
+	pub const fn sentence_capitalization(&self) -> bool {
+		self.sentence_capitalization
+	}
+	/// Gets how hyphenated compounds are translated.
+	#[must_use]
+	pub const fn hyphenation(&self) -> Hyphenation {
+		self.hyphenation
+	}
+	/// Gets how elided words are translated.
+	#[must_use]
+	pub const fn elision(&self) -> Elision {
+		self.elision
+	}
+	/// Gets how English contractions are translated.
+	#[must_use]
+	pub const fn contraction(&self) -> Contraction {
+		self.contraction
+	}
+	/// Gets the language numbers are spelled out in, if any.
+	#[must_use]
+	pub const fn numbers(&self) -> Option<Language> {
+		self.numbers
+	}
+	/// Gets the words with a fixed translation.
+	#[must_use]
+	pub const fn exceptions(&self) -> &Exceptions {
+		&self.exceptions
+	}
 
 	/// Returns the pig latin translation of a string.
 	pub fn to_pig_latin<S: Into<String>>(&self, s: S) -> String {
-		s.into()
-			.split_word_bounds()
-			.map(|word| self.word_to_case_matched_pig_latin(word))
+		self.to_cached_pig_latin(&s.into(), None)
+	}
+
+	/// Returns the pig latin translation of a string, looking words up in a cache if any.
+	pub(crate) fn to_cached_pig_latin(&self, s: &str, cache: Option<&WordCache>) -> String {
+		self.translate_pieces(s, cache)
+			.into_iter()
+			.map(|(_, pig)| pig)
 			.collect::<Vec<String>>()
 			.concat()
 	}
 
+	/// Returns the pig latin translation of a string, and the spans mapping each word and
+	/// separator of the input to its translation.
+	///
+	/// The spans are in order, and cover the input and the translation without gaps. Offsets are
+	/// in bytes.
+	///
+	/// ```
+	/// # use porcus::{PigLatinTransformer, Span};
+	/// let transformer = PigLatinTransformer::default();
+	/// let (pig, spans) = transformer.to_pig_latin_with_spans("Pig latin!");
+	/// assert_eq!(pig, "Igpay atinlay!");
+	/// assert_eq!(
+	///     spans,
+	///     vec![
+	///         Span { input: 0..3, output: 0..5 },
+	///         Span { input: 3..4, output: 5..6 },
+	///         Span { input: 4..9, output: 6..13 },
+	///         Span { input: 9..10, output: 13..14 },
+	///     ]
+	/// );
+	/// ```
+	#[must_use]
+	pub fn to_pig_latin_with_spans(&self, s: &str) -> (String, Vec<Span>) {
+		let pieces = self.translate_pieces(s, None);
+		let mut pig = String::with_capacity(pieces.iter().map(|(_, pig)| pig.len()).sum());
+		let mut input_start = 0;
+		let spans = pieces
+			.into_iter()
+			.map(|(word, translation)| {
+				let input = input_start..input_start + word.len();
+				let output = pig.len()..pig.len() + translation.len();
+				input_start = input.end;
+				pig.push_str(&translation);
+				Span { input, output }
+			})
+			.collect();
+		(pig, spans)
+	}
+
+	/// Splits a string into words and separators, with their normalized translations.
+	fn translate_pieces<'a>(
+		&self,
+		s: &'a str,
+		cache: Option<&WordCache>,
+	) -> Vec<(&'a str, String)> {
+		let pieces = if self.sentence_capitalization {
+			s.split_sentence_bounds()
+				.flat_map(|sentence| self.sentence_to_pig_latin(sentence, cache))
+				.collect()
+		} else {
+			self.split_words(s)
+				.into_iter()
+				.map(|(word, kind)| (word, self.token_to_pig_latin(word, kind, cache)))
+				.collect::<Vec<(&str, String)>>()
+		};
+		pieces
+			.into_iter()
+			.map(|(word, pig)| (word, self.normalization.normalize(pig)))
+			.collect()
+	}
+
+	/// Returns the pig latin translations of many strings, in parallel and in input order.
+	///
+	/// ```
+	/// # use porcus::PigLatinTransformer;
+	/// let transformer = PigLatinTransformer::default();
+	/// assert_eq!(
+	///     transformer.transform_many(vec!["pig", "latin"]),
+	///     vec!["igpay", "atinlay"]
+	/// );
+	/// ```
+	#[cfg(feature = "rayon")]
+	pub fn transform_many<I>(&self, inputs: I) -> Vec<String>
+	where
+		I: IntoParallelIterator,
+		I::Item: Into<String>,
+	{
+		inputs
+			.into_par_iter()
+			.map(|input| self.to_pig_latin(input))
+			.collect()
+	}
+
+	/// Returns the pig latin translation of bytes in UTF-8.
+	///
+	/// Invalid UTF-8 sequences are passed through unchanged, and split words around them.
+	///
+	/// ```
+	/// # use porcus::PigLatinTransformer;
+	/// let transformer = PigLatinTransformer::default();
+	/// assert_eq!(transformer.to_pig_latin_bytes(b"pig\xfflatin"), b"igpay\xffatinlay");
+	/// ```
+	#[must_use]
+	pub fn to_pig_latin_bytes(&self, bytes: &[u8]) -> Vec<u8> {
+		encoding::map_utf8_runs(bytes, |text| self.to_pig_latin(text))
+	}
+
+	/// Splits a string into words, and atomic tokens unless they are split.
+	fn split_words<'a>(&self, s: &'a str) -> Vec<(&'a str, Option<TokenKind>)> {
+		if self.token_policy == TokenPolicy::Split {
+			return self
+				.split_text(s)
+				.into_iter()
+				.map(|word| (word, None))
+				.collect();
+		}
+		token::split(s)
+			.into_iter()
+			.flat_map(|(segment, kind)| match kind {
+				Some(_) => vec![(segment, kind)],
+				None => self
+					.split_text(segment)
+					.into_iter()
+					.map(|word| (word, None))
+					.collect(),
+			})
+			.collect()
+	}
+
+	fn split_text<'a>(&self, s: &'a str) -> Vec<&'a str> {
+		let words = match self.numbers {
+			Some(language) => number::split_words(s, language),
+			None => ascii::split_word_bounds(s).unwrap_or_else(|| s.split_word_bounds().collect()),
+		};
+		match self.hyphenation {
+			Hyphenation::PerPart => words,
+			Hyphenation::Whole => compound::join_hyphenated(s, words),
+		}
+	}
+
+	/// Translates a sentence, then capitalizes its first word like in the input, and capitalized
+	/// words which lost their capital, e.g. `McDonald` → `onaldMcDay` → `OnaldMcDay`.
+	fn sentence_to_pig_latin<'a>(
+		&self,
+		sentence: &'a str,
+		cache: Option<&WordCache>,
+	) -> Vec<(&'a str, String)> {
+		let mut is_sentence_start = true;
+		self.split_words(sentence)
+			.into_iter()
+			.map(|(word, kind)| {
+				let pig = self.token_to_pig_latin(word, kind, cache);
+				let Some(is_capital) = first_cased_char(word).map(|c| !c.is_lowercase()) else {
+					return (word, pig);
+				};
+				if kind.is_some() {
+					is_sentence_start = false;
+					return (word, pig);
+				}
+				let pig = if is_sentence_start {
+					self.capitalize_first(pig, is_capital)
+				} else if is_capital && self.locale.detect_case(word) != Case::Upper {
+					self.capitalize_first(pig, true)
+				} else {
+					pig
+				};
+				is_sentence_start = false;
+				(word, pig)
+			})
+			.collect()
+	}
+
+	/// Sets the case of the first cased letter of a word.
+	fn capitalize_first(&self, s: String, is_capital: bool) -> String {
+		let Some((index, first)) = s
+			.grapheme_indices(true)
+			.find(|(_, grapheme)| first_cased_char(grapheme).is_some())
+		else {
+			return s;
+		};
+		let case = if is_capital {
+			Case::Sentence
+		} else {
+			Case::Lower
+		};
+		let mapped = self.locale.to_case(first, case);
+		if mapped == first {
+			return s;
+		}
+		format!("{}{}{}", &s[..index], mapped, &s[index + first.len()..])
+	}
+
+	fn words_to_pig_latin(&self, s: &str, cache: Option<&WordCache>) -> String {
+		self.split_words(s)
+			.into_iter()
+			.map(|(word, kind)| self.token_to_pig_latin(word, kind, cache))
+			.collect::<Vec<String>>()
+			.concat()
+	}
+
+	fn token_to_pig_latin(
+		&self,
+		s: &str,
+		kind: Option<TokenKind>,
+		cache: Option<&WordCache>,
+	) -> String {
+		match kind {
+			None => match cache {
+				Some(cache) if s.starts_with(char::is_alphabetic) => self.cached_word(s, cache),
+				_ => self.word_to_case_matched_pig_latin(s),
+			},
+			Some(TokenKind::Hashtag) if self.token_policy == TokenPolicy::TransformHashtags => {
+				format!("#{}", self.words_to_pig_latin(&s[1..], cache))
+			}
+			Some(_) => s.to_string(),
+		}
+	}
+
+	/// Translates a word through the cache, keyed in lowercase so that the cases of a word share
+	/// one entry. Words whose translation depends on where their capitals are, such as `McDonald`
+	/// or kept and spelled acronyms, and words which lowercase irreversibly, such as `ẞ`, are
+	/// cached as written.
+	fn cached_word(&self, s: &str, cache: &WordCache) -> String {
+		let case = self.locale.detect_case(s);
+		let lower = self.locale.to_lowercase(s);
+		let is_case_sensitive = match case {
+			Case::Lower => false,
+			Case::Upper if self.acronym_policy != AcronymPolicy::Transform => true,
+			Case::Upper | Case::Sentence => self.locale.to_case(lower.as_str(), case) != s,
+			Case::Mixed => true,
+		};
+		if is_case_sensitive {
+			return cache.get_or_insert_with(s, || self.word_to_case_matched_pig_latin(s));
+		}
+		let pig = cache.get_or_insert_with(&lower, || self.word_to_case_matched_pig_latin(&lower));
+		self.locale.to_case(pig, case)
+	}
+
 	fn word_to_case_matched_pig_latin(&self, s: &str) -> String {
+		if !self.exceptions.is_empty() && s.starts_with(char::is_alphabetic) {
+			if let Some(exception) = self.exceptions.apply_with_locale(s, self.locale) {
+				return exception;
+			}
+		}
+		if let Some(words) = self
+			.numbers
+			.and_then(|language| number::verbalize(s, language))
+		{
+			return self.to_pig_latin(words);
+		}
 		if should_skip_word(s) {
 			return s.to_string();
 		}
+		match self.acronym_policy {
+			AcronymPolicy::Keep => {
+				if let Some(length) = acronym::kept_acronym_length(s) {
+					if length == s.len() {
+						return s.to_string();
+					}
+					let rest = self.word_to_case_matched_pig_latin(&s[length..]);
+					return format!("{}{}", &s[..length], rest);
+				}
+			}
+			AcronymPolicy::Spell if acronym::is_acronym(s, self.orthography) => {
+				return self.spell(s);
+			}
+			_ => {}
+		}
+		if let Some(pig) = self.compound_to_pig_latin(s) {
+			return pig;
+		}
 
 		let pig = self.word_to_uncased_pig_latin(s);
-		case::to_case(pig, case::detect_case(s))
+		self.locale.to_case(pig, self.locale.detect_case(s))
+	}
+
+	/// Translates words starting with an elision or ending with a contraction clitic, unless
+	/// they are rotated as a whole.
+	fn compound_to_pig_latin(&self, s: &str) -> Option<String> {
+		if self.elision != Elision::Rotate {
+			if let Some((elided, rest)) = compound::split_elision(s) {
+				let rest = self.word_to_case_matched_pig_latin(rest);
+				if self.elision == Elision::Attach {
+					return Some(format!("{}{}", elided, rest));
+				}
+				let letters = elided.trim_end_matches(['\'', '’']);
+				return Some(format!(
+					"{}{}{}",
+					self.word_to_case_matched_pig_latin(letters),
+					&elided[letters.len()..],
+					rest
+				));
+			}
+		}
+		if self.contraction == Contraction::KeepClitic {
+			if let Some((stem, clitic)) = compound::split_contraction(s) {
+				return Some(format!(
+					"{}{}",
+					self.word_to_case_matched_pig_latin(stem),
+					clitic
+				));
+			}
+		}
+		None
+	}
+
+	/// Translates the name of each letter of a word, e.g. `TV` → `tee vee` → `eetay-eevay`.
+	fn spell(&self, s: &str) -> String {
+		let letter_names = self.orthography.letter_names();
+		s.graphemes(true)
+			.filter(|grapheme| *grapheme != ".")
+			.map(|letter| {
+				let letter = letter.to_lowercase();
+				let name = letter
+					.nfd()
+					.next()
+					.and_then(|base| letter_names.get(&base))
+					.map_or(letter.as_str(), |name| name);
+				self.to_pig_latin(name)
+			})
+			.collect::<Vec<String>>()
+			.join("-")
 	}
 
 	fn word_to_uncased_pig_latin(&self, s: &str) -> String {
 		let graphemes = &s.graphemes(true).collect::<Vec<&str>>();
 
-		let mut prefix_length = 0;
-		while has_consonant_at(graphemes, prefix_length) {
-			prefix_length += 1;
+		if let Some(single_letter_suffix) = self.dialect.single_letter_suffix() {
+			if graphemes.len() == 1 {
+				return format!(
+					"{}{}",
+					s,
+					style::restyle(single_letter_suffix, style::detect(s))
+				);
+			}
 		}
 
+		// Styled letters are matched as plain letters, which have the same grapheme boundaries,
+		// but moved with their style.
+		let plain = style::to_plain(s);
+		let plain_graphemes = &plain.graphemes(true).collect::<Vec<&str>>();
+		if let Some(pig) = self.respelled_pig_latin(&plain, plain_graphemes) {
+			return style::restyle(&pig, style::detect(s));
+		}
+		let prefix_length = self.phonetic_onset_length(&plain, plain_graphemes);
 		if prefix_length == 0 {
-			return format!("{}{}", s, self.vowel_suffix);
+			return format!(
+				"{}{}",
+				s,
+				style::restyle(&self.vowel_suffix, style::detect(s))
+			);
 		}
 		let prefix = &graphemes[0..prefix_length];
 		let suffix = &graphemes[prefix_length..];
-		format!(
+		let rotated = format!("{}{}", suffix.concat(), prefix.concat());
+		let consonant_suffix = style::restyle(&self.consonant_suffix, style::detect(&rotated));
+		format!("{}{}", rotated, consonant_suffix)
+	}
+
+	/// Translates a word whose initial consonant sounds have no consonant letter, e.g. the /w/ of
+	/// `one`, by respelling its pronunciation: `W AH1 N` → `un` + `w` + `ay`.
+	///
+	/// Returns `None` for other words, whose spelled onset can be rotated.
+	fn respelled_pig_latin(&self, s: &str, graphemes: &[&str]) -> Option<String> {
+		let lexicon = self.lexicon.as_ref()?;
+		let onset_length = match (lexicon.onset_length(s)?, self.dialect.onset()) {
+			(0, _) => return None,
+			(_, Onset::Single) => 1,
+			(onset_length, _) => onset_length,
+		};
+		if self.onset_length(graphemes) > 0 {
+			return None;
+		}
+		let alphabet = lexicon.alphabet();
+		let respelled = lexicon
+			.get(s)?
+			.iter()
+			.map(|phoneme| alphabet.respell(phoneme))
+			.collect::<Option<Vec<&str>>>()?;
+		Some(format!(
 			"{}{}{}",
-			suffix.concat(),
-			prefix.concat(),
+			respelled[onset_length..].concat(),
+			respelled[..onset_length].concat(),
 			self.consonant_suffix
-		)
+		))
+	}
+
+	/// Finds the spelled onset, but only rotates it if the lexicon says the word starts with a
+	/// consonant sound.
+	fn phonetic_onset_length(&self, s: &str, graphemes: &[&str]) -> usize {
+		let spelled_length = self.onset_length(graphemes);
+		match self
+			.lexicon
+			.as_ref()
+			.and_then(|lexicon| lexicon.onset_length(s))
+		{
+			Some(0) => 0,
+			_ => spelled_length,
+		}
+	}
+
+	fn onset_length(&self, graphemes: &[&str]) -> usize {
+		let mut length = 0;
+		loop {
+			if self.hyphenation == Hyphenation::Whole
+				&& length > 0
+				&& graphemes
+					.get(length)
+					.is_some_and(|grapheme| compound::is_hyphen(grapheme))
+				&& self.has_consonant_at(graphemes, length + 1)
+			{
+				length += 1;
+			}
+			length += match self.orthography.multigraph_at(graphemes, length) {
+				Some((unit_length, multigraph)) => match multigraph.char_type {
+					CharType::Consonant => unit_length,
+					_ => break,
+				},
+				None if !self.has_consonant_at(graphemes, length) => break,
+				None if self.dialect.qu_as_unit() && is_qu_at(graphemes, length) => 2,
+				None => 1,
+			};
+			if self.dialect.onset() == Onset::Single {
+				break;
+			}
+		}
+		length
+	}
+
+	fn has_consonant_at(&self, graphemes: &[&str], index: usize) -> bool {
+		match char_type::get_char_type_at(graphemes, index) {
+			CharType::Consonant => true,
+			CharType::Ambiguous => match self.dialect.y_rule() {
+				YRule::Contextual => matches!(
+					char_type::get_char_type_at(graphemes, index + 1),
+					CharType::Vowel
+				),
+				YRule::Initial => index == 0,
+				YRule::Vowel => false,
+			},
+			_ => false,
+		}
+	}
+}
+
+/// Word or separator of a text, mapped to its translation.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct Span {
+	/// Byte range in the input.
+	pub input: Range<usize>,
+	/// Byte range of the translation in the output.
+	pub output: Range<usize>,
+}
+
+/// Stand-in for the word cache, which needs `std` for its lock. It has no values, so words are
+/// never cached without `std`.
+#[cfg(not(feature = "std"))]
+pub(crate) enum WordCache {}
+
+#[cfg(not(feature = "std"))]
+impl WordCache {
+	fn get_or_insert_with<F: FnOnce() -> String>(&self, _word: &str, _f: F) -> String {
+		match *self {}
 	}
 }
 
+fn first_cased_char(s: &str) -> Option<char> {
+	s.chars().find(|c| c.is_lowercase() || c.is_uppercase())
+}
+
 fn should_skip_word(s: &str) -> bool {
-	s.chars().next().map_or(true, |first_char| {
-		first_char.script().full_name() != "Latin"
+	s.chars().next().is_none_or(|first_char| {
+		style::unstyle(first_char).is_none() && first_char.script().full_name() != "Latin"
 	})
 }
 
-fn has_consonant_at(graphemes: &[&str], index: usize) -> bool {
-	match char_type::get_char_type_at(graphemes, index) {
-		CharType::Consonant => true,
-		CharType::Ambiguous => matches!(
-			char_type::get_char_type_at(graphemes, index + 1),
-			CharType::Vowel
-		),
-		_ => false,
-	}
+fn is_qu_at(graphemes: &[&str], index: usize) -> bool {
+	let is_letter_at = |index: usize, letter: char| {
+		graphemes.get(index).is_some_and(|grapheme| {
+			grapheme
+				.nfd()
+				.next()
+				.is_some_and(|c| c.to_lowercase().eq(letter.to_lowercase()))
+		})
+	};
+	is_letter_at(index, 'q') && is_letter_at(index + 1, 'u')
 }
 
 #[cfg(test)]
@@ -189,6 +970,131 @@ mod test_getters {
 		let transformer = PigLatinTransformer::default();
 		assert_eq!(transformer.consonant_suffix(), "ay");
 		assert_eq!(transformer.vowel_suffix(), "way");
+
+		let transformer = transformer
+			.with_consonant_suffix("C")
+			.with_vowel_suffix("V");
+		assert_eq!(transformer, PigLatinTransformer::new("C", "V"));
+	}
+
+	#[test]
+	fn dialect() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.dialect(), &Dialect::default());
+
+		let dialect = Dialect::default().with_qu_as_unit(true);
+		let transformer = PigLatinTransformer::default().with_dialect(dialect.clone());
+		assert_eq!(transformer.dialect(), &dialect);
+	}
+
+	#[test]
+	fn display() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.to_string(), "Pig Latin <C+ay V+way, standard>");
+
+		let transformer = transformer.with_dialect(Dialect::from_preset("qu-cluster").unwrap());
+		assert_eq!(
+			transformer.to_string(),
+			"Pig Latin <C+ay V+way, qu-cluster>"
+		);
+
+		let transformer = transformer.with_dialect(
+			Dialect::from_preset("playground")
+				.unwrap()
+				.with_qu_as_unit(false),
+		);
+		assert_eq!(transformer.to_string(), "Pig Latin <C+ay V+way, custom>");
+	}
+
+	#[test]
+	fn orthography() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.orthography(), Orthography::Generic);
+
+		let transformer = PigLatinTransformer::default().with_orthography(Orthography::Welsh);
+		assert_eq!(transformer.orthography(), Orthography::Welsh);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn lexicon() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.lexicon(), None);
+
+		let lexicon = Lexicon::from_cmudict("A  AH0\n".as_bytes()).unwrap();
+		let transformer = PigLatinTransformer::default().with_lexicon(lexicon.clone());
+		assert_eq!(transformer.lexicon(), Some(&lexicon));
+	}
+
+	#[test]
+	fn acronym_policy() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.acronym_policy(), AcronymPolicy::Transform);
+
+		let transformer = PigLatinTransformer::default().with_acronym_policy(AcronymPolicy::Spell);
+		assert_eq!(transformer.acronym_policy(), AcronymPolicy::Spell);
+	}
+
+	#[test]
+	fn exceptions() {
+		let transformer = PigLatinTransformer::default();
+		assert!(transformer.exceptions().is_empty());
+
+		let exceptions = Exceptions::new().with_kept("NASA");
+		let transformer = PigLatinTransformer::default().with_exceptions(exceptions.clone());
+		assert_eq!(transformer.exceptions(), &exceptions);
+	}
+
+	#[test]
+	fn locale() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.locale(), Locale::Root);
+
+		let transformer = PigLatinTransformer::default().with_locale(Locale::Turkish);
+		assert_eq!(transformer.locale(), Locale::Turkish);
+	}
+
+	#[test]
+	fn sentence_capitalization() {
+		let transformer = PigLatinTransformer::default();
+		assert!(!transformer.sentence_capitalization());
+
+		let transformer = PigLatinTransformer::default().with_sentence_capitalization(true);
+		assert!(transformer.sentence_capitalization());
+	}
+
+	#[test]
+	fn normalization() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.normalization(), Normalization::None);
+
+		let transformer = PigLatinTransformer::default().with_normalization(Normalization::Nfd);
+		assert_eq!(transformer.normalization(), Normalization::Nfd);
+	}
+
+	#[test]
+	fn token_policy() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.token_policy(), TokenPolicy::Split);
+
+		let transformer = PigLatinTransformer::default().with_token_policy(TokenPolicy::Keep);
+		assert_eq!(transformer.token_policy(), TokenPolicy::Keep);
+	}
+
+	#[test]
+	fn compounds() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.hyphenation(), Hyphenation::PerPart);
+		assert_eq!(transformer.elision(), Elision::Rotate);
+		assert_eq!(transformer.contraction(), Contraction::Whole);
+
+		let transformer = PigLatinTransformer::default()
+			.with_hyphenation(Hyphenation::Whole)
+			.with_elision(Elision::Separate)
+			.with_contraction(Contraction::KeepClitic);
+		assert_eq!(transformer.hyphenation(), Hyphenation::Whole);
+		assert_eq!(transformer.elision(), Elision::Separate);
+		assert_eq!(transformer.contraction(), Contraction::KeepClitic);
 	}
 }
 
@@ -284,6 +1190,28 @@ mod test_to_pig_latin {
 		assert_pig_latin("The Rebbe z״ya", "Ethay Ebberay az״yay");
 	}
 
+	#[test]
+	fn styled() {
+		assert_pig_latin("𝐇𝐞𝐥𝐥𝐨", "𝐄𝐥𝐥𝐨𝐡𝐚𝐲");
+		assert_pig_latin("𝘩𝘦𝘭𝘭𝘰 𝘸𝘰𝘳𝘭𝘥", "𝘦𝘭𝘭𝘰𝘩𝘢𝘺 𝘰𝘳𝘭𝘥𝘸𝘢𝘺");
+		assert_pig_latin("ⓗⓔⓛⓛⓞ", "ⓔⓛⓛⓞⓗⓐⓨ");
+		assert_pig_latin("🄷🄴🄻🄻🄾", "🄴🄻🄻🄾🄷🄰🅈");
+		assert_pig_latin("𝓔𝓰𝓰", "𝓔𝓰𝓰𝔀𝓪𝔂");
+		assert_pig_latin("ℋ𝒶𝓅𝓅𝓎", "𝒜𝓅𝓅𝓎𝒽𝒶𝓎");
+		assert_pig_latin("𝐬𝐜𝐡𝐨𝐨𝐥", "𝐨𝐨𝐥𝐬𝐜𝐡𝐚𝐲");
+		assert_pig_latin("𝐇ello", "Ello𝐡ay");
+	}
+
+	#[test]
+	fn variant_suffixes() {
+		assert_pig_latin("ｎｉｘ", "ｉｘｎａｙ");
+		assert_pig_latin("ＥＧＧ", "ＥＧＧＷＡＹ");
+		assert_pig_latin("ɴɪx", "ɪxɴᴀʏ");
+		assert_pig_latin("ʜᴇʟʟᴏ", "ᴇʟʟᴏʜᴀʏ");
+		assert_pig_latin("ᵐᵉʷ", "ᵐᵉʷᵃʸ");
+		assert_pig_latin("ₙₒ", "ₒₙₐy");
+	}
+
 	#[test]
 	fn custom_suffixes() {
 		let transformer = PigLatinTransformer::new("yay", "-hay");
@@ -291,4 +1219,764 @@ mod test_to_pig_latin {
 
 		assert_eq!(result, "Ellohyay, egg-hay!");
 	}
+
+	#[test]
+	fn bytes() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.to_pig_latin_bytes(b""), b"");
+		assert_eq!(transformer.to_pig_latin_bytes(b"\xc0\xaf"), b"\xc0\xaf");
+		assert_eq!(
+			transformer.to_pig_latin_bytes(b"Caf\xc3\xa9 \xe9 "),
+			b"Af\xc3\xa9cay \xe9 "
+		);
+		assert_eq!(
+			transformer.to_pig_latin_bytes(b"\xef\xbb\xbfHello\r\n\x00world\xed\xa0\x80"),
+			b"\xef\xbb\xbfEllohay\r\n\x00orldway\xed\xa0\x80"
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_normalization {
+	use super::*;
+
+	const CORPUS: &[&str] = &[
+		"Café crème à l’œuf, s’il vous plaît.",
+		"Ça déçoit ÉNORMÉMENT, Ève.",
+		"Straße, Öl, Übermut, GRÜẞE.",
+		"Žluťoučký kůň úpěl ďábelské ódy.",
+		"Łódź, Gdańsk, Kraków.",
+		"Ñandú, güero, año, PINGÜINO.",
+		"Ångström, Øre, Þór, Æsir.",
+		"Cường, Nguyễn, Đà Nẵng.",
+		"Ǆungla, ǅep, ǈudi, ǋegoš.",
+		"Ἀθῆναι and Ĳsselmeer, ĳs.",
+		"ŷ ÿ ý, Ýmir, ŶŴ.",
+		"La TV à 1er mai coûte 21,5 €.",
+	];
+
+	fn transformers() -> Vec<PigLatinTransformer> {
+		let mut transformers = Orthography::NAMES
+			.iter()
+			.filter_map(|name| Orthography::from_name(name))
+			.map(|orthography| PigLatinTransformer::default().with_orthography(orthography))
+			.collect::<Vec<PigLatinTransformer>>();
+		transformers.push(
+			PigLatinTransformer::new("é", "ève")
+				.with_acronym_policy(AcronymPolicy::Spell)
+				.with_numbers(Language::French)
+				.with_exceptions(Exceptions::new().with_replacement("crème", "èmecray")),
+		);
+		transformers
+	}
+
+	#[test]
+	fn nfc_and_nfd_are_equivalent() {
+		for transformer in transformers() {
+			for text in CORPUS {
+				let nfc = transformer.to_pig_latin(text.nfc().collect::<String>());
+				let nfd = transformer.to_pig_latin(text.nfd().collect::<String>());
+				assert_eq!(
+					nfc.nfc().collect::<String>(),
+					nfd.nfc().collect::<String>(),
+					"{} ({})",
+					text,
+					transformer.orthography()
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn output_forms() {
+		for normalization in [Normalization::Nfc, Normalization::Nfd, Normalization::Nfkc] {
+			let transformer = PigLatinTransformer::default().with_normalization(normalization);
+			for text in CORPUS {
+				let nfc = transformer.to_pig_latin(text.nfc().collect::<String>());
+				let nfd = transformer.to_pig_latin(text.nfd().collect::<String>());
+				assert_eq!(nfc, nfd, "{} ({})", text, normalization);
+				assert_eq!(nfc, normalization.normalize(nfc.as_str()));
+			}
+		}
+	}
+
+	#[test]
+	fn untouched_segments_are_kept() {
+		let transformer = PigLatinTransformer::default();
+		let input = "e\u{301}te\u{301} ! \u{3b1}\u{301}λφα, \u{1100}\u{1161}\u{11a8} ² cafe\u{301}";
+		assert_eq!(
+			transformer.to_pig_latin(input),
+			"e\u{301}te\u{301}way ! \u{3b1}\u{301}λφα, \u{1100}\u{1161}\u{11a8} ² afe\u{301}cay"
+		);
+	}
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test_serde {
+	use super::*;
+	use crate::dialect::{Onset, YRule};
+
+	fn round_trip(transformer: &PigLatinTransformer) -> PigLatinTransformer {
+		let serialized = toml::to_string(transformer).unwrap();
+		toml::from_str(&serialized).unwrap()
+	}
+
+	#[test]
+	fn defaults() {
+		let transformer: PigLatinTransformer = toml::from_str("").unwrap();
+		assert_eq!(transformer, PigLatinTransformer::default());
+	}
+
+	#[test]
+	fn preset_dialect() {
+		let transformer = PigLatinTransformer::new("ei", "wei")
+			.with_dialect(Dialect::from_preset("playground").unwrap())
+			.with_orthography(Orthography::Welsh);
+		let serialized = toml::to_string(&transformer).unwrap();
+		assert!(serialized.contains("dialect = \"playground\""));
+		assert!(serialized.contains("orthography = \"welsh\""));
+		assert_eq!(round_trip(&transformer), transformer);
+	}
+
+	#[test]
+	fn custom_dialect() {
+		let dialect = Dialect::default()
+			.with_onset(Onset::Single)
+			.with_y_rule(YRule::Vowel);
+		let transformer = PigLatinTransformer::default().with_dialect(dialect);
+		assert_eq!(round_trip(&transformer), transformer);
+
+		let transformer: PigLatinTransformer =
+			toml::from_str("[dialect]\nonset = \"single\"\nsingle-letter-suffix = \"yay\"\n")
+				.unwrap();
+		assert_eq!(transformer.to_pig_latin("a string"), "ayay tringsay");
+	}
+
+	#[test]
+	fn unknown_names() {
+		assert!(toml::from_str::<PigLatinTransformer>("dialect = \"klingon\"").is_err());
+		assert!(toml::from_str::<PigLatinTransformer>("orthography = \"vulcan\"").is_err());
+		assert!(toml::from_str::<PigLatinTransformer>("[dialect]\ntypo = true").is_err());
+		assert!(toml::from_str::<PigLatinTransformer>("vowel-sufix = \"x\"").is_err());
+		assert!(toml::from_str::<PigLatinTransformer>("format = \"markdown\"").is_err());
+	}
+
+	#[test]
+	fn exceptions() {
+		let exceptions = Exceptions::new()
+			.with_kept("NASA")
+			.with_replacement("GitHub", "ItHubgay");
+		let transformer = PigLatinTransformer::default().with_exceptions(exceptions);
+		assert_eq!(round_trip(&transformer), transformer);
+		assert!(!toml::to_string(&PigLatinTransformer::default())
+			.unwrap()
+			.contains("exceptions"));
+
+		let transformer: PigLatinTransformer =
+			toml::from_str("[exceptions]\nkeep = [\"iPhone\"]\nreplace = { pig = \"swine\" }\n")
+				.unwrap();
+		assert_eq!(transformer.to_pig_latin("Pig iPhone"), "Swine iPhone");
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn lexicon_is_skipped() {
+		let lexicon = Lexicon::from_cmudict("HOUR  AW1 ER0\n".as_bytes()).unwrap();
+		let transformer = PigLatinTransformer::default().with_lexicon(lexicon);
+		assert!(round_trip(&transformer).lexicon().is_none());
+	}
+}
+
+#[cfg(test)]
+mod test_dialects {
+	use super::*;
+
+	fn assert_dialect(dialect: Dialect, input: &str, expected: &str) {
+		let transformer = PigLatinTransformer::default().with_dialect(dialect);
+		assert_eq!(transformer.to_pig_latin(input), expected);
+	}
+
+	#[test]
+	fn single_consonant_onset() {
+		let dialect = Dialect::default().with_onset(Onset::Single);
+		assert_dialect(dialect.clone(), "string", "tringsay");
+		assert_dialect(dialect.clone(), "nix", "ixnay");
+		assert_dialect(dialect.clone(), "egg", "eggway");
+		assert_dialect(dialect, "hmm", "mmhay");
+	}
+
+	#[test]
+	fn qu_as_unit() {
+		let dialect = Dialect::default().with_qu_as_unit(true);
+		assert_dialect(dialect.clone(), "queen", "eenquay");
+		assert_dialect(dialect.clone(), "Quiet", "Ietquay");
+		assert_dialect(dialect.clone(), "squeeze", "eezesquay");
+		assert_dialect(dialect.clone(), "QUIT", "ITQUAY");
+		assert_dialect(dialect.clone(), "qat", "atqay");
+		assert_dialect(dialect.clone(), "aqua", "aquaway");
+		let dialect = dialect.with_onset(Onset::Single);
+		assert_dialect(dialect, "queen", "eenquay");
+	}
+
+	#[test]
+	fn y_rules() {
+		let dialect = Dialect::default().with_y_rule(YRule::Initial);
+		assert_dialect(dialect.clone(), "yak", "akyay");
+		assert_dialect(dialect.clone(), "Yvonne", "Onneyvay");
+		assert_dialect(dialect.clone(), "rhythm", "ythmrhay");
+		assert_dialect(dialect, "byrå", "yråbay");
+
+		let dialect = Dialect::default().with_y_rule(YRule::Vowel);
+		assert_dialect(dialect.clone(), "yak", "yakway");
+		assert_dialect(dialect, "rhythm", "ythmrhay");
+	}
+
+	#[test]
+	fn single_letter_suffix() {
+		let dialect = Dialect::default().with_single_letter_suffix("yay");
+		assert_dialect(dialect.clone(), "a", "ayay");
+		assert_dialect(dialect.clone(), "I", "Iyay");
+		assert_dialect(dialect.clone(), "b", "byay");
+		assert_dialect(dialect, "an", "anway");
+	}
+
+	#[test]
+	fn presets() {
+		let playground = Dialect::from_preset("playground").unwrap();
+		assert_dialect(playground, "A quiet rhythm", "Ayay ietquay ythmrhay");
+	}
+}
+
+#[cfg(test)]
+mod test_sentences {
+	use super::*;
+
+	fn assert_sentences(transformer: PigLatinTransformer, input: &str, expected: &str) {
+		let transformer = transformer.with_sentence_capitalization(true);
+		assert_eq!(transformer.to_pig_latin(input), expected);
+	}
+
+	#[test]
+	fn unchanged() {
+		let transformer = PigLatinTransformer::default();
+		assert_sentences(
+			transformer.clone(),
+			"Simon met the Rebbe. He left!",
+			"Imonsay etmay ethay Ebberay. Ehay eftlay!",
+		);
+		assert_sentences(
+			transformer.clone(),
+			"\"Yes,\" she said. 'Tis true.",
+			"\"Esyay,\" eshay aidsay. 'Istay uetray.",
+		);
+		assert_sentences(
+			transformer,
+			"iPhone sales. eBay too.",
+			"iPhoneway alessay. eBayway ootay.",
+		);
+	}
+
+	#[test]
+	fn proper_nouns() {
+		let transformer = PigLatinTransformer::default();
+		assert_sentences(
+			transformer.clone(),
+			"I met McDonald and MacLeod.",
+			"Iway etmay OnaldMcDay andway AcLeodMay.",
+		);
+		assert_eq!(
+			transformer.to_pig_latin("I met McDonald."),
+			"Iway etmay onaldMcDay."
+		);
+	}
+
+	#[test]
+	fn sentence_starts() {
+		let transformer = PigLatinTransformer::default().with_acronym_policy(AcronymPolicy::Spell);
+		assert_sentences(
+			transformer,
+			"TV is on. Watch TV!",
+			"Eetay-eevay isway onway. Atchway eetay-eevay!",
+		);
+
+		let transformer = PigLatinTransformer::default().with_numbers(Language::English);
+		assert_sentences(transformer, "3 cats. Two.", "eethray atscay. Otway.");
+
+		let exceptions = Exceptions::new().with_replacement("mcdonald", "cdonaldmay");
+		let transformer = PigLatinTransformer::default().with_exceptions(exceptions);
+		assert_sentences(
+			transformer,
+			"McDonald? McDonald.",
+			"Cdonaldmay? Cdonaldmay.",
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_locales {
+	use super::*;
+
+	fn assert_locale(locale: Locale, input: &str, expected: &str) {
+		let transformer = PigLatinTransformer::default().with_locale(locale);
+		assert_eq!(transformer.to_pig_latin(input), expected);
+	}
+
+	#[test]
+	fn turkish() {
+		assert_locale(Locale::Turkish, "Kitap", "İtapkay");
+		assert_locale(Locale::Turkish, "KIZ", "IZKAY");
+		assert_locale(Locale::Root, "Kitap", "Itapkay");
+	}
+
+	#[test]
+	fn dutch() {
+		assert_locale(Locale::Dutch, "Kijk", "IJkkay");
+		assert_locale(Locale::Dutch, "IJs", "IJsway");
+		assert_locale(Locale::Root, "Kijk", "Ijkkay");
+	}
+
+	#[test]
+	fn exceptions() {
+		let exceptions = Exceptions::new().with_replacement("izmir", "smirnay");
+		let transformer = PigLatinTransformer::default()
+			.with_locale(Locale::Turkish)
+			.with_exceptions(exceptions);
+		assert_eq!(transformer.to_pig_latin("İzmir IZMIR"), "Smirnay IZMIRWAY");
+	}
+}
+
+#[cfg(test)]
+mod test_orthographies {
+	use super::*;
+
+	fn assert_orthography(orthography: Orthography, input: &str, expected: &str) {
+		let transformer = PigLatinTransformer::default().with_orthography(orthography);
+		assert_eq!(transformer.to_pig_latin(input), expected);
+	}
+
+	#[test]
+	fn english() {
+		assert_orthography(Orthography::English, "quiet", "ietquay");
+		assert_orthography(Orthography::English, "Queen", "Eenquay");
+		assert_orthography(Orthography::English, "squeak", "eaksquay");
+		assert_orthography(Orthography::English, "three", "eethray");
+		assert_orthography(Orthography::English, "guess", "essguay");
+		assert_orthography(Orthography::English, "guitar", "itarguay");
+		assert_orthography(Orthography::English, "guard", "uardgay");
+	}
+
+	#[test]
+	fn french() {
+		assert_orthography(Orthography::French, "guerre", "erreguay");
+		assert_orthography(Orthography::French, "Guy", "Yguay");
+		assert_orthography(Orthography::French, "gare", "aregay");
+		assert_orthography(Orthography::French, "qui", "iquay");
+	}
+
+	#[test]
+	fn dutch() {
+		assert_orthography(Orthography::Dutch, "ijs", "ijsway");
+		assert_orthography(Orthography::Dutch, "IJsland", "IJslandway");
+		assert_orthography(Orthography::Dutch, "schrijven", "ijvenschray");
+	}
+
+	#[test]
+	fn welsh() {
+		assert_orthography(Orthography::Welsh, "llan", "anllay");
+		assert_orthography(Orthography::Welsh, "wyth", "wythway");
+		assert_orthography(Orthography::Welsh, "Cymru", "Ymrucay");
+	}
+
+	#[test]
+	fn single_onset() {
+		let dialect = Dialect::default().with_onset(Onset::Single);
+		let transformer = PigLatinTransformer::default()
+			.with_dialect(dialect)
+			.with_orthography(Orthography::Czech);
+		assert_eq!(transformer.to_pig_latin("chléb"), "lébchay");
+		assert_eq!(transformer.to_pig_latin("džbán"), "bándžay");
+
+		let dialect = Dialect::default().with_onset(Onset::Single);
+		let transformer = PigLatinTransformer::default()
+			.with_dialect(dialect)
+			.with_orthography(Orthography::English);
+		assert_eq!(transformer.to_pig_latin("thread"), "readthay");
+	}
+}
+
+#[cfg(test)]
+mod test_acronyms {
+	use super::*;
+
+	fn assert_acronyms(policy: AcronymPolicy, input: &str, expected: &str) {
+		let transformer = PigLatinTransformer::default().with_acronym_policy(policy);
+		assert_eq!(transformer.to_pig_latin(input), expected);
+	}
+
+	#[test]
+	fn transform() {
+		assert_acronyms(AcronymPolicy::Transform, "TV", "TVAY");
+		assert_acronyms(AcronymPolicy::Transform, "U.S.A.", "U.S.AWAY.");
+	}
+
+	#[test]
+	fn keep() {
+		assert_acronyms(AcronymPolicy::Keep, "FBI and HTML", "FBI andway HTML");
+		assert_acronyms(AcronymPolicy::Keep, "U.S.A.", "U.S.A.");
+		assert_acronyms(AcronymPolicy::Keep, "NASA", "NASA");
+		assert_acronyms(AcronymPolicy::Keep, "TV9मराठी", "TV9मराठी");
+		assert_acronyms(AcronymPolicy::Keep, "HTML5 pages", "HTML5 agespay");
+	}
+
+	#[test]
+	fn spell() {
+		assert_acronyms(AcronymPolicy::Spell, "TV", "eetay-eevay");
+		assert_acronyms(
+			AcronymPolicy::Spell,
+			"the U.S.A.",
+			"ethay ouyay-essway-ayway.",
+		);
+		assert_acronyms(AcronymPolicy::Spell, "WC", "oubleday-ouyay-eesay");
+		assert_acronyms(AcronymPolicy::Spell, "ØK", "øway-aykay");
+	}
+
+	#[test]
+	fn spell_in_orthography() {
+		let transformer = PigLatinTransformer::default()
+			.with_acronym_policy(AcronymPolicy::Spell)
+			.with_orthography(Orthography::French);
+		assert_eq!(
+			transformer.to_pig_latin("la SNCF"),
+			"alay esseway-enneway-écay-effeway"
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_numbers {
+	use super::*;
+
+	#[test]
+	fn english() {
+		let transformer = PigLatinTransformer::default().with_numbers(Language::English);
+		assert_eq!(transformer.to_pig_latin("42"), "ortyfay-otway");
+		assert_eq!(
+			transformer.to_pig_latin("The 3rd of 1,000."),
+			"Ethay irdthay ofway oneway ousandthay."
+		);
+		assert_eq!(
+			transformer.to_pig_latin("In 1984"),
+			"Inway ineteennay eightyway-ourfay"
+		);
+		assert_eq!(transformer.to_pig_latin("x86"), "86xay");
+	}
+
+	#[test]
+	fn french() {
+		let transformer = PigLatinTransformer::default().with_numbers(Language::French);
+		assert_eq!(transformer.to_pig_latin("1ʳᵉ"), "emièrepray");
+		assert_eq!(transformer.to_pig_latin("0,5"), "érozay irgulevay inqcay");
+		assert_eq!(
+			transformer.to_pig_latin("2 000 ans"),
+			"euxday illemay answay"
+		);
+	}
+
+	#[test]
+	fn disabled() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(transformer.to_pig_latin("42 ans"), "42 answay");
+	}
+}
+
+#[cfg(test)]
+mod test_exceptions {
+	use super::*;
+
+	fn transformer() -> PigLatinTransformer {
+		let exceptions = Exceptions::new()
+			.with_kept("iPhone")
+			.with_kept("NASA")
+			.with_replacement("GitHub", "ItHubgay")
+			.with_replacement("œuf", "œufsay");
+		PigLatinTransformer::default().with_exceptions(exceptions)
+	}
+
+	#[test]
+	fn kept() {
+		let transformer = transformer();
+		assert_eq!(transformer.to_pig_latin("My iPhone"), "Ymay iPhone");
+		assert_eq!(transformer.to_pig_latin("NASA nasa Nasa"), "NASA nasa Nasa");
+	}
+
+	#[test]
+	fn replaced() {
+		let transformer = transformer();
+		assert_eq!(transformer.to_pig_latin("on GitHub."), "onway ItHubgay.");
+		assert_eq!(transformer.to_pig_latin("GITHUB"), "ITHUBGAY");
+		assert_eq!(transformer.to_pig_latin("Œuf, œuf"), "Œufsay, œufsay");
+	}
+
+	#[test]
+	fn whole_words_only() {
+		let transformer = transformer();
+		assert_eq!(transformer.to_pig_latin("iPhones"), "iPhonesway");
+		assert_eq!(transformer.to_pig_latin("NASA-led"), "NASA-edlay");
+	}
+
+	#[test]
+	fn letters_only() {
+		let exceptions = Exceptions::new()
+			.with_replacement("42", "forty-two")
+			.with_replacement("!", "?");
+		let transformer = PigLatinTransformer::default().with_exceptions(exceptions);
+		assert_eq!(transformer.to_pig_latin("42 pigs!"), "42 igspay!");
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_lexicon {
+	use super::*;
+
+	const CMUDICT: &str = "\
+HOUR  AW1 ER0
+HONEST  AA1 N AH0 S T
+ONE  W AH1 N
+UNIFORM  Y UW1 N AH0 F AO2 R M
+KNIGHT  N AY1 T
+PSYCHOLOGY  S AY0 K AA1 L AH0 JH IY0
+YES  Y EH1 S
+YTTRIUM  IH1 T R IY0 AH0 M
+";
+
+	fn assert_phonetic(input: &str, expected: &str) {
+		let lexicon = Lexicon::from_cmudict(CMUDICT.as_bytes()).unwrap();
+		let transformer = PigLatinTransformer::default().with_lexicon(lexicon);
+		assert_eq!(transformer.to_pig_latin(input), expected);
+	}
+
+	#[test]
+	fn silent_consonants() {
+		assert_phonetic("hour", "hourway");
+		assert_phonetic("Honest", "Honestway");
+	}
+
+	#[test]
+	fn consonant_sounds() {
+		assert_phonetic("knight", "ightknay");
+		assert_phonetic("psychology", "ychologypsay");
+		assert_phonetic("yes", "esyay");
+	}
+
+	#[test]
+	fn vowel_sounds() {
+		assert_phonetic("yttrium", "yttriumway");
+	}
+
+	#[test]
+	fn unspelled_consonant_sounds() {
+		assert_phonetic("one", "unway");
+		assert_phonetic("One", "Unway");
+		assert_phonetic("uniform", "oonufawrmyay");
+	}
+
+	#[test]
+	fn unknown_words() {
+		assert_phonetic("hand", "andhay");
+		assert_phonetic("egg", "eggway");
+	}
+}
+
+#[cfg(test)]
+mod test_compounds {
+	use super::*;
+
+	#[test]
+	fn hyphenation() {
+		let per_part = PigLatinTransformer::default();
+		assert_eq!(per_part.to_pig_latin("T-shirt"), "Tay-irtshay");
+		assert_eq!(
+			per_part.to_pig_latin("mother-in-law"),
+			"othermay-inway-awlay"
+		);
+
+		let whole = PigLatinTransformer::default().with_hyphenation(Hyphenation::Whole);
+		assert_eq!(whole.to_pig_latin("T-shirt"), "Irtt-shay");
+		assert_eq!(whole.to_pig_latin("mother-in-law"), "other-in-lawmay");
+		assert_eq!(whole.to_pig_latin("e-mail"), "e-mailway");
+		assert_eq!(whole.to_pig_latin("a - b"), "away - bay");
+	}
+
+	#[test]
+	fn elision() {
+		let rotate = PigLatinTransformer::default();
+		assert_eq!(rotate.to_pig_latin("L'eau"), "Eaul'ay");
+
+		let attach = PigLatinTransformer::default().with_elision(Elision::Attach);
+		assert_eq!(attach.to_pig_latin("L'eau"), "L'eauway");
+		assert_eq!(attach.to_pig_latin("d’Artagnan"), "d’Artagnanway");
+		assert_eq!(attach.to_pig_latin("dell'anno"), "dell'annoway");
+
+		let separate = PigLatinTransformer::default().with_elision(Elision::Separate);
+		assert_eq!(separate.to_pig_latin("L'eau"), "Lay'eauway");
+		assert_eq!(separate.to_pig_latin("jusqu’ici"), "usqujay’iciway");
+		assert_eq!(separate.to_pig_latin("O'Brien"), "O'Brienway");
+	}
+
+	#[test]
+	fn contraction() {
+		let whole = PigLatinTransformer::default();
+		assert_eq!(whole.to_pig_latin("don't"), "on'tday");
+
+		let keep_clitic = PigLatinTransformer::default().with_contraction(Contraction::KeepClitic);
+		assert_eq!(keep_clitic.to_pig_latin("don't"), "odayn't");
+		assert_eq!(keep_clitic.to_pig_latin("We'll"), "Eway'll");
+		assert_eq!(keep_clitic.to_pig_latin("WE’LL"), "EWAY’LL");
+		assert_eq!(keep_clitic.to_pig_latin("o'clock"), "o'clockway");
+	}
+}
+
+#[cfg(test)]
+mod test_tokens {
+	use super::*;
+
+	const CHAT: &str = "@alice see https://example.com/pig-latin or mail bob@example.com :smile: #piglatin v1.2.3 ~/notes.txt";
+
+	#[test]
+	fn split() {
+		let transformer = PigLatinTransformer::default();
+		assert_eq!(
+			transformer.to_pig_latin("https://example.com"),
+			"httpsay://example.comway"
+		);
+	}
+
+	#[test]
+	fn keep() {
+		let transformer = PigLatinTransformer::default().with_token_policy(TokenPolicy::Keep);
+		assert_eq!(
+			transformer.to_pig_latin(CHAT),
+			"@alice eesay https://example.com/pig-latin orway ailmay bob@example.com :smile: #piglatin v1.2.3 ~/notes.txt"
+		);
+	}
+
+	#[test]
+	fn transform_hashtags() {
+		let transformer =
+			PigLatinTransformer::default().with_token_policy(TokenPolicy::TransformHashtags);
+		assert_eq!(
+			transformer.to_pig_latin("#bacon and #PigLatin @bacon"),
+			"#aconbay andway #igLatinPay @bacon"
+		);
+	}
+
+	#[test]
+	fn sentences() {
+		let transformer = PigLatinTransformer::default()
+			.with_token_policy(TokenPolicy::Keep)
+			.with_sentence_capitalization(true);
+		assert_eq!(
+			transformer.to_pig_latin("@Alice hello. 1.2.3 is out."),
+			"@Alice ellohay. 1.2.3 isway outway."
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_spans {
+	use super::*;
+
+	const TEXTS: &[&str] = &[
+		"",
+		"Pig latin!",
+		"Hello, Mr. McDonald. The NASA TV is on.\nÇa déçoit, Ève.",
+		"@Alice see https://example.com/pig-latin #PigLatin",
+		"well-known re-entry, l’œuf and don't",
+		"e\u{301}te\u{301} ﬁ² 21,5 € cafe\u{301}",
+	];
+
+	fn transformers() -> Vec<PigLatinTransformer> {
+		vec![
+			PigLatinTransformer::default(),
+			PigLatinTransformer::new("é", "ève")
+				.with_sentence_capitalization(true)
+				.with_normalization(Normalization::Nfc)
+				.with_numbers(Language::French),
+			PigLatinTransformer::default()
+				.with_token_policy(TokenPolicy::TransformHashtags)
+				.with_hyphenation(Hyphenation::Whole)
+				.with_normalization(Normalization::Nfkc),
+		]
+	}
+
+	#[test]
+	fn cover_input_and_output() {
+		for transformer in transformers() {
+			for text in TEXTS {
+				let (pig, spans) = transformer.to_pig_latin_with_spans(text);
+				assert_eq!(pig, transformer.to_pig_latin(*text), "{:?}", text);
+				let mut input_end = 0;
+				let mut output_end = 0;
+				for span in &spans {
+					assert_eq!(span.input.start, input_end, "{:?}", text);
+					assert_eq!(span.output.start, output_end, "{:?}", text);
+					assert!(text.is_char_boundary(span.input.end));
+					assert!(pig.is_char_boundary(span.output.end));
+					input_end = span.input.end;
+					output_end = span.output.end;
+				}
+				assert_eq!((input_end, output_end), (text.len(), pig.len()));
+			}
+		}
+	}
+
+	#[test]
+	fn words() {
+		let transformer = PigLatinTransformer::default().with_sentence_capitalization(true);
+		let text = "Hello world. Bye";
+		let (pig, spans) = transformer.to_pig_latin_with_spans(text);
+		let pairs = spans
+			.into_iter()
+			.map(|span| (&text[span.input], &pig[span.output]))
+			.collect::<Vec<(&str, &str)>>();
+		assert_eq!(
+			pairs,
+			vec![
+				("Hello", "Ellohay"),
+				(" ", " "),
+				("world", "orldway"),
+				(".", "."),
+				(" ", " "),
+				("Bye", "Ebyay"),
+			]
+		);
+	}
+}
+#[cfg(all(test, feature = "rayon"))]
+mod test_transform_many {
+	use super::*;
+	use rayon::iter::IntoParallelRefIterator;
+
+	#[test]
+	fn order_is_kept() {
+		let transformer = PigLatinTransformer::default().with_sentence_capitalization(true);
+		let inputs = (0..1000)
+			.map(|index| format!("Line {} of the Corpus.", index))
+			.collect::<Vec<String>>();
+		let expected = inputs
+			.iter()
+			.map(|input| transformer.to_pig_latin(input.as_str()))
+			.collect::<Vec<String>>();
+		assert_eq!(
+			transformer.transform_many(inputs.par_iter().cloned()),
+			expected
+		);
+		assert_eq!(transformer.transform_many(inputs), expected);
+	}
+
+	#[test]
+	fn shared_between_threads() {
+		fn assert_send_sync<T: Send + Sync>() {}
+		assert_send_sync::<PigLatinTransformer>();
+	}
 }
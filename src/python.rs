@@ -0,0 +1,161 @@
+//! Python bindings, built as the `porcus` extension module with
+//! [maturin](https://www.maturin.rs/).
+//!
+//! ```python
+//! import porcus
+//!
+//! transformer = porcus.PigLatinTransformer("ay", "way")
+//! assert transformer.to_pig_latin("Pig latin") == "Igpay atinlay"
+//! assert porcus.detect_case("Pig") == porcus.Case.SENTENCE
+//! assert porcus.get_char_type_at(["y", "e", "s"], 0) == porcus.CharType.AMBIGUOUS
+//! ```
+//!
+//! Strings are borrowed from Python rather than copied, and the GIL is released while
+//! translating, so that other Python threads keep running.
+
+use crate::case::{self, Case};
+use crate::char_type::{self, CharType};
+use crate::{PigLatinTransformer, DEFAULT_CONSONANT_SUFFIX, DEFAULT_VOWEL_SUFFIX};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
+
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+/// Converter from text to pig latin, with its suffixes.
+#[pyclass(name = "PigLatinTransformer", module = "porcus", frozen)]
+struct PyPigLatinTransformer {
+	transformer: PigLatinTransformer,
+}
+
+#[pymethods]
+impl PyPigLatinTransformer {
+	#[new]
+	#[pyo3(signature = (consonant_suffix = DEFAULT_CONSONANT_SUFFIX, vowel_suffix = DEFAULT_VOWEL_SUFFIX))]
+	fn new(consonant_suffix: &str, vowel_suffix: &str) -> Self {
+		Self {
+			transformer: PigLatinTransformer::new(consonant_suffix, vowel_suffix),
+		}
+	}
+
+	/// Suffix appended to words starting with a consonant.
+	#[getter]
+	fn consonant_suffix(&self) -> &str {
+		self.transformer.consonant_suffix()
+	}
+
+	/// Suffix appended to words starting with a vowel.
+	#[getter]
+	fn vowel_suffix(&self) -> &str {
+		self.transformer.vowel_suffix()
+	}
+
+	/// Returns the pig latin translation of a string.
+	fn to_pig_latin(&self, py: Python<'_>, s: &str) -> String {
+		py.detach(|| self.transformer.to_cached_pig_latin(s, None))
+	}
+
+	/// Returns the pig latin translations of many strings, in order.
+	fn transform_many(&self, py: Python<'_>, strings: Vec<PyBackedStr>) -> Vec<String> {
+		let transform = |s: &PyBackedStr| self.transformer.to_cached_pig_latin(s, None);
+		#[cfg(feature = "rayon")]
+		return py.detach(|| strings.par_iter().map(transform).collect());
+		#[cfg(not(feature = "rayon"))]
+		return py.detach(|| strings.iter().map(transform).collect());
+	}
+
+	fn __repr__(&self) -> String {
+		format!(
+			"PigLatinTransformer({:?}, {:?})",
+			self.transformer.consonant_suffix(),
+			self.transformer.vowel_suffix()
+		)
+	}
+
+	fn __str__(&self) -> String {
+		self.transformer.to_string()
+	}
+}
+
+/// Returns the case of a word.
+#[pyfunction]
+fn detect_case(s: &str) -> Case {
+	case::detect_case(s)
+}
+
+/// Returns the vowel-or-consonant classification of a grapheme, given its neighbours.
+#[pyfunction]
+fn get_char_type_at(graphemes: Vec<PyBackedStr>, index: usize) -> CharType {
+	let graphemes = graphemes.iter().map(|g| &**g).collect::<Vec<&str>>();
+	char_type::get_char_type_at(&graphemes, index)
+}
+
+#[pymodule]
+fn porcus(module: &Bound<'_, PyModule>) -> PyResult<()> {
+	module.add_class::<PyPigLatinTransformer>()?;
+	module.add_class::<Case>()?;
+	module.add_class::<CharType>()?;
+	module.add_function(wrap_pyfunction!(detect_case, module)?)?;
+	module.add_function(wrap_pyfunction!(get_char_type_at, module)?)?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod test_python {
+	use super::*;
+	use pyo3::types::PyDict;
+
+	/// Runs Python code with the module imported as `porcus`.
+	fn run(code: &core::ffi::CStr) {
+		Python::initialize();
+		Python::attach(|py| {
+			let globals = PyDict::new(py);
+			globals
+				.set_item("porcus", pyo3::wrap_pymodule!(porcus)(py))
+				.unwrap();
+			if let Err(error) = py.run(code, Some(&globals), None) {
+				error.display(py);
+				panic!("Python code failed");
+			}
+		});
+	}
+
+	#[test]
+	fn transformer() {
+		run(cr#"
+transformer = porcus.PigLatinTransformer("ey", "yey")
+assert transformer.to_pig_latin("egg nix") == "eggyey ixney"
+assert transformer.transform_many(["Pig", "latin"]) == ["Igpey", "atinley"]
+assert (transformer.consonant_suffix, transformer.vowel_suffix) == ("ey", "yey")
+assert repr(transformer) == 'PigLatinTransformer("ey", "yey")'
+assert porcus.PigLatinTransformer().to_pig_latin("à l’œuf") == "àway œufl’ay"
+"#);
+	}
+
+	#[test]
+	fn enums() {
+		run(cr#"
+assert porcus.detect_case("iPhone") == porcus.Case.MIXED
+assert porcus.detect_case("PIG") == porcus.Case.UPPER
+assert porcus.get_char_type_at(["w"], 0) == porcus.CharType.CONSONANT
+assert porcus.get_char_type_at(["y", "e", "s"], 0) == porcus.CharType.AMBIGUOUS
+assert porcus.get_char_type_at([], 0) == porcus.CharType.EMPTY
+assert str(porcus.Case.LOWER) == "lowercase"
+assert str(porcus.CharType.NON_LATIN) == "non-latin"
+"#);
+	}
+
+	#[test]
+	fn type_errors() {
+		run(cr#"
+try:
+    porcus.PigLatinTransformer().transform_many("not a list")
+    assert False
+except TypeError:
+    pass
+"#);
+	}
+}
@@ -0,0 +1,466 @@
+//! Styled letters, such as mathematical bold or circled letters.
+//!
+//! Styled letters are often used to format text where markup is not available. They are
+//! classified as the letter they decompose to, and keep their style through translation.
+//!
+//! Small capitals, superscript and subscript letters are letters in their own right, e.g. in IPA,
+//! and are classified as such. Suffixes appended to words written with them still match their
+//! style.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::style::{self, Style};
+//!
+//! assert_eq!(style::unstyle('𝐇'), Some(('H', Style::Bold)));
+//! assert_eq!(Style::Circled.apply('a'), Some('ⓐ'));
+//! assert_eq!(style::to_plain("𝐇𝐞𝐥𝐥𝐨"), "Hello");
+//! ```
+
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::iter;
+use unicode_normalization::UnicodeNormalization;
+
+/// Typographic style of a letter.
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Style {
+	/// Mathematical bold, e.g. `𝐀`.
+	Bold,
+	/// Mathematical italic, e.g. `𝐴`.
+	Italic,
+	/// Mathematical bold italic, e.g. `𝑨`.
+	BoldItalic,
+	/// Mathematical script, e.g. `𝒜`.
+	Script,
+	/// Mathematical bold script, e.g. `𝓐`.
+	BoldScript,
+	/// Mathematical fraktur, e.g. `𝔄`.
+	Fraktur,
+	/// Mathematical double-struck, e.g. `𝔸`.
+	DoubleStruck,
+	/// Mathematical bold fraktur, e.g. `𝕬`.
+	BoldFraktur,
+	/// Mathematical sans-serif, e.g. `𝖠`.
+	SansSerif,
+	/// Mathematical sans-serif bold, e.g. `𝗔`.
+	SansSerifBold,
+	/// Mathematical sans-serif italic, e.g. `𝘈`.
+	SansSerifItalic,
+	/// Mathematical sans-serif bold italic, e.g. `𝘼`.
+	SansSerifBoldItalic,
+	/// Mathematical monospace, e.g. `𝙰`.
+	Monospace,
+	/// Circled, e.g. `Ⓐ`.
+	Circled,
+	/// Negative circled, e.g. `🅐`. Only capitals exist.
+	NegativeCircled,
+	/// Squared, e.g. `🄰`. Only capitals exist.
+	Squared,
+	/// Negative squared, e.g. `🅰`. Only capitals exist.
+	NegativeSquared,
+	/// Fullwidth, e.g. `Ａ`.
+	Fullwidth,
+	/// Small capitals, e.g. `ᴀ`. Only some letters exist, with no case.
+	SmallCapital,
+	/// Superscript, e.g. `ᵃ`. Only some letters exist.
+	Superscript,
+	/// Subscript, e.g. `ₐ`. Only some small letters exist.
+	Subscript,
+}
+
+/// First code points of the capital and small letters of each style.
+const RANGES: &[(Style, Option<u32>, Option<u32>)] = &[
+	(Style::Bold, Some(0x1d400), Some(0x1d41a)),
+	(Style::Italic, Some(0x1d434), Some(0x1d44e)),
+	(Style::BoldItalic, Some(0x1d468), Some(0x1d482)),
+	(Style::Script, Some(0x1d49c), Some(0x1d4b6)),
+	(Style::BoldScript, Some(0x1d4d0), Some(0x1d4ea)),
+	(Style::Fraktur, Some(0x1d504), Some(0x1d51e)),
+	(Style::DoubleStruck, Some(0x1d538), Some(0x1d552)),
+	(Style::BoldFraktur, Some(0x1d56c), Some(0x1d586)),
+	(Style::SansSerif, Some(0x1d5a0), Some(0x1d5ba)),
+	(Style::SansSerifBold, Some(0x1d5d4), Some(0x1d5ee)),
+	(Style::SansSerifItalic, Some(0x1d608), Some(0x1d622)),
+	(Style::SansSerifBoldItalic, Some(0x1d63c), Some(0x1d656)),
+	(Style::Monospace, Some(0x1d670), Some(0x1d68a)),
+	(Style::Circled, Some(0x24b6), Some(0x24d0)),
+	(Style::NegativeCircled, Some(0x1f150), None),
+	(Style::Squared, Some(0x1f130), None),
+	(Style::NegativeSquared, Some(0x1f170), None),
+	(Style::Fullwidth, Some(0xff21), Some(0xff41)),
+];
+
+/// Styled letters encoded outside of their range, in the Letterlike Symbols block. Their place in
+/// the range is left unassigned.
+const HOLES: &[(Style, char, char)] = &[
+	(Style::Italic, 'h', 'ℎ'),
+	(Style::Script, 'B', 'ℬ'),
+	(Style::Script, 'E', 'ℰ'),
+	(Style::Script, 'F', 'ℱ'),
+	(Style::Script, 'H', 'ℋ'),
+	(Style::Script, 'I', 'ℐ'),
+	(Style::Script, 'L', 'ℒ'),
+	(Style::Script, 'M', 'ℳ'),
+	(Style::Script, 'R', 'ℛ'),
+	(Style::Script, 'e', 'ℯ'),
+	(Style::Script, 'g', 'ℊ'),
+	(Style::Script, 'o', 'ℴ'),
+	(Style::Fraktur, 'C', 'ℭ'),
+	(Style::Fraktur, 'H', 'ℌ'),
+	(Style::Fraktur, 'I', 'ℑ'),
+	(Style::Fraktur, 'R', 'ℜ'),
+	(Style::Fraktur, 'Z', 'ℨ'),
+	(Style::DoubleStruck, 'C', 'ℂ'),
+	(Style::DoubleStruck, 'H', 'ℍ'),
+	(Style::DoubleStruck, 'N', 'ℕ'),
+	(Style::DoubleStruck, 'P', 'ℙ'),
+	(Style::DoubleStruck, 'Q', 'ℚ'),
+	(Style::DoubleStruck, 'R', 'ℝ'),
+	(Style::DoubleStruck, 'Z', 'ℤ'),
+];
+
+/// Letters with a variant used to style text, which are not classified as their plain letter.
+const VARIANTS: &[(Style, char, char)] = &[
+	(Style::SmallCapital, 'a', 'ᴀ'),
+	(Style::SmallCapital, 'b', 'ʙ'),
+	(Style::SmallCapital, 'c', 'ᴄ'),
+	(Style::SmallCapital, 'd', 'ᴅ'),
+	(Style::SmallCapital, 'e', 'ᴇ'),
+	(Style::SmallCapital, 'f', 'ꜰ'),
+	(Style::SmallCapital, 'g', 'ɢ'),
+	(Style::SmallCapital, 'h', 'ʜ'),
+	(Style::SmallCapital, 'i', 'ɪ'),
+	(Style::SmallCapital, 'j', 'ᴊ'),
+	(Style::SmallCapital, 'k', 'ᴋ'),
+	(Style::SmallCapital, 'l', 'ʟ'),
+	(Style::SmallCapital, 'm', 'ᴍ'),
+	(Style::SmallCapital, 'n', 'ɴ'),
+	(Style::SmallCapital, 'o', 'ᴏ'),
+	(Style::SmallCapital, 'p', 'ᴘ'),
+	(Style::SmallCapital, 'q', 'ꞯ'),
+	(Style::SmallCapital, 'r', 'ʀ'),
+	(Style::SmallCapital, 's', 'ꜱ'),
+	(Style::SmallCapital, 't', 'ᴛ'),
+	(Style::SmallCapital, 'u', 'ᴜ'),
+	(Style::SmallCapital, 'v', 'ᴠ'),
+	(Style::SmallCapital, 'w', 'ᴡ'),
+	(Style::SmallCapital, 'y', 'ʏ'),
+	(Style::SmallCapital, 'z', 'ᴢ'),
+	(Style::Superscript, 'A', 'ᴬ'),
+	(Style::Superscript, 'B', 'ᴮ'),
+	(Style::Superscript, 'D', 'ᴰ'),
+	(Style::Superscript, 'E', 'ᴱ'),
+	(Style::Superscript, 'G', 'ᴳ'),
+	(Style::Superscript, 'H', 'ᴴ'),
+	(Style::Superscript, 'I', 'ᴵ'),
+	(Style::Superscript, 'J', 'ᴶ'),
+	(Style::Superscript, 'K', 'ᴷ'),
+	(Style::Superscript, 'L', 'ᴸ'),
+	(Style::Superscript, 'M', 'ᴹ'),
+	(Style::Superscript, 'N', 'ᴺ'),
+	(Style::Superscript, 'O', 'ᴼ'),
+	(Style::Superscript, 'P', 'ᴾ'),
+	(Style::Superscript, 'R', 'ᴿ'),
+	(Style::Superscript, 'T', 'ᵀ'),
+	(Style::Superscript, 'U', 'ᵁ'),
+	(Style::Superscript, 'V', 'ⱽ'),
+	(Style::Superscript, 'W', 'ᵂ'),
+	(Style::Superscript, 'a', 'ᵃ'),
+	(Style::Superscript, 'b', 'ᵇ'),
+	(Style::Superscript, 'c', 'ᶜ'),
+	(Style::Superscript, 'd', 'ᵈ'),
+	(Style::Superscript, 'e', 'ᵉ'),
+	(Style::Superscript, 'f', 'ᶠ'),
+	(Style::Superscript, 'g', 'ᵍ'),
+	(Style::Superscript, 'h', 'ʰ'),
+	(Style::Superscript, 'i', 'ⁱ'),
+	(Style::Superscript, 'j', 'ʲ'),
+	(Style::Superscript, 'k', 'ᵏ'),
+	(Style::Superscript, 'l', 'ˡ'),
+	(Style::Superscript, 'm', 'ᵐ'),
+	(Style::Superscript, 'n', 'ⁿ'),
+	(Style::Superscript, 'o', 'ᵒ'),
+	(Style::Superscript, 'p', 'ᵖ'),
+	(Style::Superscript, 'r', 'ʳ'),
+	(Style::Superscript, 's', 'ˢ'),
+	(Style::Superscript, 't', 'ᵗ'),
+	(Style::Superscript, 'u', 'ᵘ'),
+	(Style::Superscript, 'v', 'ᵛ'),
+	(Style::Superscript, 'w', 'ʷ'),
+	(Style::Superscript, 'x', 'ˣ'),
+	(Style::Superscript, 'y', 'ʸ'),
+	(Style::Superscript, 'z', 'ᶻ'),
+	(Style::Subscript, 'a', 'ₐ'),
+	(Style::Subscript, 'e', 'ₑ'),
+	(Style::Subscript, 'h', 'ₕ'),
+	(Style::Subscript, 'i', 'ᵢ'),
+	(Style::Subscript, 'j', 'ⱼ'),
+	(Style::Subscript, 'k', 'ₖ'),
+	(Style::Subscript, 'l', 'ₗ'),
+	(Style::Subscript, 'm', 'ₘ'),
+	(Style::Subscript, 'n', 'ₙ'),
+	(Style::Subscript, 'o', 'ₒ'),
+	(Style::Subscript, 'p', 'ₚ'),
+	(Style::Subscript, 'r', 'ᵣ'),
+	(Style::Subscript, 's', 'ₛ'),
+	(Style::Subscript, 't', 'ₜ'),
+	(Style::Subscript, 'u', 'ᵤ'),
+	(Style::Subscript, 'v', 'ᵥ'),
+	(Style::Subscript, 'x', 'ₓ'),
+];
+
+impl fmt::Display for Style {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Bold => "bold",
+			Self::Italic => "italic",
+			Self::BoldItalic => "bold italic",
+			Self::Script => "script",
+			Self::BoldScript => "bold script",
+			Self::Fraktur => "fraktur",
+			Self::DoubleStruck => "double-struck",
+			Self::BoldFraktur => "bold fraktur",
+			Self::SansSerif => "sans-serif",
+			Self::SansSerifBold => "sans-serif bold",
+			Self::SansSerifItalic => "sans-serif italic",
+			Self::SansSerifBoldItalic => "sans-serif bold italic",
+			Self::Monospace => "monospace",
+			Self::Circled => "circled",
+			Self::NegativeCircled => "negative circled",
+			Self::Squared => "squared",
+			Self::NegativeSquared => "negative squared",
+			Self::Fullwidth => "fullwidth",
+			Self::SmallCapital => "small capital",
+			Self::Superscript => "superscript",
+			Self::Subscript => "subscript",
+		})
+	}
+}
+
+impl Style {
+	/// Gets the styled form of a basic Latin letter.
+	///
+	/// Styles with only capitals use them for small letters too, and small capitals are used for
+	/// both cases. Returns `None` for other characters, and letters missing from the style.
+	///
+	/// ```
+	/// # use porcus::style::Style;
+	/// assert_eq!(Style::Bold.apply('a'), Some('𝐚'));
+	/// assert_eq!(Style::Script.apply('B'), Some('ℬ'));
+	/// assert_eq!(Style::Squared.apply('a'), Some('🄰'));
+	/// assert_eq!(Style::SmallCapital.apply('A'), Some('ᴀ'));
+	/// assert_eq!(Style::Subscript.apply('y'), None);
+	/// assert_eq!(Style::Bold.apply('é'), None);
+	/// ```
+	#[must_use]
+	pub fn apply(self, letter: char) -> Option<char> {
+		if !letter.is_ascii_alphabetic() {
+			return None;
+		}
+		if let Some(&(_, _, styled)) = HOLES
+			.iter()
+			.find(|&&(style, plain, _)| style == self && plain == letter)
+		{
+			return Some(styled);
+		}
+		let letter = match self {
+			Self::SmallCapital => letter.to_ascii_lowercase(),
+			_ => letter,
+		};
+		if let Some(&(_, _, styled)) = VARIANTS
+			.iter()
+			.find(|&&(style, plain, _)| style == self && plain == letter)
+		{
+			return Some(styled);
+		}
+		let &(_, upper_start, lower_start) = RANGES.iter().find(|(style, ..)| *style == self)?;
+		let (start, offset) = if letter.is_ascii_lowercase() {
+			(
+				lower_start.or(upper_start)?,
+				u32::from(letter) - u32::from('a'),
+			)
+		} else {
+			(
+				upper_start.or(lower_start)?,
+				u32::from(letter) - u32::from('A'),
+			)
+		};
+		char::from_u32(start + offset)
+	}
+}
+
+/// Gets the basic Latin letter and style of a styled letter.
+///
+/// Returns `None` for unstyled characters.
+///
+/// ```
+/// # use porcus::style::{self, Style};
+/// assert_eq!(style::unstyle('𝘩'), Some(('h', Style::SansSerifItalic)));
+/// assert_eq!(style::unstyle('ℎ'), Some(('h', Style::Italic)));
+/// assert_eq!(style::unstyle('🅷'), Some(('H', Style::NegativeSquared)));
+/// assert_eq!(style::unstyle('h'), None);
+/// assert_eq!(style::unstyle('𝛂'), None);
+/// ```
+#[must_use]
+pub fn unstyle(c: char) -> Option<(char, Style)> {
+	if c.is_ascii() {
+		return None;
+	}
+	if let Some(&(style, plain, _)) = HOLES.iter().find(|&&(.., styled)| styled == c) {
+		return Some((plain, style));
+	}
+	let code = u32::from(c);
+	RANGES
+		.iter()
+		.find_map(|&(style, upper_start, lower_start)| {
+			let offset = |start: Option<u32>| {
+				start
+					.and_then(|start| code.checked_sub(start))
+					.filter(|offset| *offset < 26)
+			};
+			let plain = match (offset(upper_start), offset(lower_start)) {
+				(Some(offset), _) => char::from_u32(u32::from('A') + offset)?,
+				(_, Some(offset)) => char::from_u32(u32::from('a') + offset)?,
+				_ => return None,
+			};
+			// Styled letters decompose to their plain letter, except negative letters which have no
+			// decomposition. Unassigned holes in ranges decompose to themselves.
+			let decomposes = iter::once(c).nfkd().eq(iter::once(plain));
+			let is_negative = matches!(style, Style::NegativeCircled | Style::NegativeSquared);
+			(decomposes || is_negative).then_some((plain, style))
+		})
+}
+
+/// Gets the style of the letters of a string, or `None` if they are unstyled.
+///
+/// This is the style of the first styled letter, if other letters have the same style or are
+/// missing from it.
+///
+/// ```
+/// # use porcus::style::{self, Style};
+/// assert_eq!(style::detect("𝐇𝐞𝐥𝐥𝐨!"), Some(Style::Bold));
+/// assert_eq!(style::detect("ɴɪx"), Some(Style::SmallCapital));
+/// assert_eq!(style::detect("ɪtɪ"), None);
+/// assert_eq!(style::detect("𝐇ello"), None);
+/// ```
+#[must_use]
+pub fn detect(s: &str) -> Option<Style> {
+	// No styled letters or variants are ASCII.
+	if s.is_ascii() {
+		return None;
+	}
+	let style = s.chars().find_map(style_of)?;
+	s.chars()
+		.filter(|c| c.is_alphabetic() || style_of(*c).is_some())
+		.all(|c| match style_of(c) {
+			Some(letter_style) => letter_style == style,
+			None => style.apply(c).is_none(),
+		})
+		.then_some(style)
+}
+
+/// Gets the style of a character, including variants of letters.
+fn style_of(c: char) -> Option<Style> {
+	if c.is_ascii() {
+		return None;
+	}
+	unstyle(c).map(|(_, style)| style).or_else(|| {
+		VARIANTS
+			.iter()
+			.find(|&&(.., styled)| styled == c)
+			.map(|&(style, ..)| style)
+	})
+}
+
+/// Applies a style to the basic Latin letters of a string. Other characters, and letters missing
+/// from the style, are unchanged.
+///
+/// ```
+/// # use porcus::style::{self, Style};
+/// assert_eq!(style::restyle("-ay", Some(Style::Bold)), "-𝐚𝐲");
+/// assert_eq!(style::restyle("way", Some(Style::Subscript)), "wₐy");
+/// assert_eq!(style::restyle("ay", None), "ay");
+/// ```
+#[must_use]
+pub fn restyle(s: &str, style: Option<Style>) -> String {
+	match style {
+		Some(style) => s.chars().map(|c| style.apply(c).unwrap_or(c)).collect(),
+		None => s.to_string(),
+	}
+}
+
+/// Replaces styled letters with basic Latin letters.
+#[must_use]
+pub fn to_plain(s: &str) -> String {
+	if s.is_ascii() {
+		return s.to_string();
+	}
+	s.chars()
+		.map(|c| unstyle(c).map_or(c, |(plain, _)| plain))
+		.collect()
+}
+
+#[cfg(test)]
+mod test_unstyle {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		for &(style, ..) in RANGES {
+			for letter in ('A'..='Z').chain('a'..='z') {
+				let styled = style.apply(letter).unwrap();
+				let (plain, unstyled) = unstyle(styled).unwrap();
+				assert_eq!(unstyled, style, "{}", styled);
+				assert_eq!(plain.to_ascii_lowercase(), letter.to_ascii_lowercase());
+			}
+		}
+	}
+
+	#[test]
+	fn variants_are_not_unstyled() {
+		for &(style, plain, styled) in VARIANTS {
+			assert!(!styled.is_ascii(), "{}", styled);
+			assert_eq!(unstyle(styled), None, "{}", styled);
+			assert_eq!(style.apply(plain), Some(styled));
+			assert_eq!(detect(&styled.to_string()), Some(style));
+		}
+	}
+
+	#[test]
+	fn holes_are_unassigned() {
+		for &(style, plain, styled) in HOLES {
+			assert_eq!(unstyle(styled), Some((plain, style)));
+			let (_, upper_start, lower_start) = RANGES.iter().find(|(s, ..)| *s == style).unwrap();
+			let (start, first) = if plain.is_ascii_lowercase() {
+				(lower_start.unwrap(), 'a')
+			} else {
+				(upper_start.unwrap(), 'A')
+			};
+			let hole = char::from_u32(start + u32::from(plain) - u32::from(first)).unwrap();
+			assert!(iter::once(hole).nfkd().eq(iter::once(hole)), "{}", style);
+		}
+	}
+
+	#[test]
+	fn not_styled() {
+		for c in ['a', 'Z', 'é', 'ᴀ', 'ᵃ', 'ₐ', 'α', '𝚨', '𝟏', '①'] {
+			assert_eq!(unstyle(c), None, "{}", c);
+		}
+	}
+}
+
+#[cfg(test)]
+mod test_detect {
+	use super::*;
+
+	#[test]
+	fn mixed_styles() {
+		assert_eq!(detect("𝐜𝐚𝐟é"), Some(Style::Bold));
+		assert_eq!(detect("𝐜𝐚𝑓"), None);
+		assert_eq!(detect("ⓗⓔⓛⓛⓞ ⓦⓞⓡⓛⓓ"), Some(Style::Circled));
+		assert_eq!(detect("ʜᴇʟʟᴏ"), Some(Style::SmallCapital));
+		assert_eq!(detect("kʰ"), None);
+		assert_eq!(detect("42"), None);
+	}
+}
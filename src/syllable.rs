@@ -0,0 +1,378 @@
+//! Splits words into syllables.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::orthography::Orthography;
+//! use porcus::syllable;
+//!
+//! let syllables = syllable::syllables("syllable", Orthography::English);
+//! let texts = syllables.iter().map(|s| s.text).collect::<Vec<&str>>();
+//! assert_eq!(texts, vec!["syl", "la", "ble"]);
+//! assert_eq!(syllables[1].graphemes, 3..5);
+//! ```
+//!
+//! # Method
+//!
+//! Each run of vowels is the nucleus of a syllable. Consonants between two nuclei are split by
+//! [onset maximization](https://en.wikipedia.org/wiki/Syllabification#Onset_maximization): the
+//! following syllable takes the longest run of consonants which may begin a word in the
+//! orthography, and the preceding syllable keeps the rest.
+//!
+//! A vowel with a diaeresis begins a nucleus of its own, as in `na·ïve`.
+//!
+//! In English, a final `e` after a consonant is silent, as in `athlete`, unless it follows a
+//! consonant and `l`, as in `table`. That consonant then begins the last syllable, as in
+//! `lit·tle`. A final `m` after `s`, `z` or `th` is a syllable of its own, as in `rhythm`.
+//!
+//! This is a rough approximation based on spelling only. It ignores morphology, e.g. `dishonest`
+//! is split as `di·sho·nest`, and otherwise treats adjacent vowels as a single nucleus, e.g.
+//! `idea` is split as `i·dea`.
+
+use crate::char_type::{self, CharType};
+use crate::orthography::Orthography;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Syllable within a word.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct Syllable<'a> {
+	/// Text of the syllable.
+	pub text: &'a str,
+	/// Indices of the graphemes of the syllable within the word.
+	pub graphemes: Range<usize>,
+}
+
+impl fmt::Display for Syllable<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.text)
+	}
+}
+
+/// Letter or multigraph.
+struct Unit {
+	graphemes: Range<usize>,
+	is_vowel: bool,
+	/// Whether the unit has a diaeresis, and so cannot continue the nucleus before it.
+	has_diaeresis: bool,
+	/// Whether the unit begins the following syllable, even if its onset is not legal.
+	begins_syllable: bool,
+}
+
+/// Splits a word into syllables.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use porcus::orthography::Orthography;
+/// # use porcus::syllable::syllables;
+/// let texts = |word| {
+///     syllables(word, Orthography::English)
+///         .iter()
+///         .map(|s| s.text)
+///         .collect::<Vec<&str>>()
+/// };
+/// assert_eq!(texts("pig"), vec!["pig"]);
+/// assert_eq!(texts("latin"), vec!["la", "tin"]);
+/// assert_eq!(texts("instruct"), vec!["in", "struct"]);
+/// ```
+///
+/// Legal onsets depend on the orthography.
+///
+/// ```
+/// # use porcus::orthography::Orthography;
+/// # use porcus::syllable::syllables;
+/// let texts = |word, orthography| {
+///     syllables(word, orthography)
+///         .iter()
+///         .map(|s| s.text)
+///         .collect::<Vec<&str>>()
+/// };
+/// assert_eq!(texts("pueblo", Orthography::Spanish), vec!["pue", "blo"]);
+/// assert_eq!(texts("Apfel", Orthography::German), vec!["A", "pfel"]);
+/// assert_eq!(texts("Apfel", Orthography::English), vec!["Ap", "fel"]);
+/// ```
+///
+/// Grapheme ranges count combining characters as part of their base letter.
+///
+/// ```
+/// # use porcus::orthography::Orthography;
+/// # use porcus::syllable::syllables;
+/// let syllables = syllables("cafe\u{301}", Orthography::French);
+/// assert_eq!(syllables[1].text, "fe\u{301}");
+/// assert_eq!(syllables[1].graphemes, 2..4);
+/// ```
+///
+/// Words without vowels are a single syllable, and the empty string has none.
+///
+/// ```
+/// # use porcus::orthography::Orthography;
+/// # use porcus::syllable::syllables;
+/// assert_eq!(syllables("hmm", Orthography::English).len(), 1);
+/// assert!(syllables("", Orthography::English).is_empty());
+/// ```
+#[must_use]
+pub fn syllables(word: &str, orthography: Orthography) -> Vec<Syllable<'_>> {
+	let graphemes = word.grapheme_indices(true).collect::<Vec<(usize, &str)>>();
+	let grapheme_strs = graphemes.iter().map(|(_, g)| *g).collect::<Vec<&str>>();
+	let units = units(&grapheme_strs, orthography);
+
+	let mut starts = vec![0];
+	let mut previous_nucleus_end = None;
+	let mut unit_index = 0;
+	while unit_index < units.len() {
+		if !units[unit_index].is_vowel {
+			unit_index += 1;
+			continue;
+		}
+		if let Some(cluster_start) = previous_nucleus_end {
+			let cluster = &units[cluster_start..unit_index];
+			let mut onset_length = longest_onset(&grapheme_strs, cluster, orthography);
+			if let Some(start) = cluster.iter().position(|unit| unit.begins_syllable) {
+				onset_length = onset_length.max(cluster.len() - start);
+			}
+			starts.push(units[unit_index - onset_length].graphemes.start);
+		}
+		unit_index += 1;
+		while unit_index < units.len()
+			&& units[unit_index].is_vowel
+			&& !units[unit_index].has_diaeresis
+		{
+			unit_index += 1;
+		}
+		previous_nucleus_end = Some(unit_index);
+	}
+
+	if graphemes.is_empty() {
+		return Vec::new();
+	}
+	starts.dedup();
+	let ends = starts
+		.iter()
+		.skip(1)
+		.copied()
+		.chain(core::iter::once(graphemes.len()));
+	starts
+		.iter()
+		.zip(ends)
+		.map(|(&start, end)| {
+			let byte_start = graphemes[start].0;
+			let byte_end = graphemes.get(end).map_or(word.len(), |(index, _)| *index);
+			Syllable {
+				text: &word[byte_start..byte_end],
+				graphemes: start..end,
+			}
+		})
+		.collect()
+}
+
+/// Checks whether a word can be read as a word rather than spelled out.
+///
+/// A word is pronounceable if it has a vowel and the consonants it starts with may begin a
+/// syllable in the orthography.
+///
+/// ```
+/// # use porcus::orthography::Orthography;
+/// # use porcus::syllable::is_pronounceable;
+/// assert!(is_pronounceable("NASA", Orthography::English));
+/// assert!(is_pronounceable("SCUBA", Orthography::English));
+/// assert!(!is_pronounceable("HTML", Orthography::English));
+/// assert!(!is_pronounceable("FBI", Orthography::English));
+/// ```
+#[must_use]
+pub fn is_pronounceable(word: &str, orthography: Orthography) -> bool {
+	let graphemes = word.graphemes(true).collect::<Vec<&str>>();
+	let units = units(&graphemes, orthography);
+	units
+		.iter()
+		.position(|unit| unit.is_vowel)
+		.is_some_and(|onset_length| {
+			onset_length <= 1
+				|| longest_onset(&graphemes, &units[..onset_length], orthography) == onset_length
+		})
+}
+
+fn units(graphemes: &[&str], orthography: Orthography) -> Vec<Unit> {
+	let mut units = Vec::new();
+	let mut index = 0;
+	while index < graphemes.len() {
+		let (length, is_vowel) = match orthography.multigraph_at(graphemes, index) {
+			Some((length, multigraph)) => (length, multigraph.char_type == CharType::Vowel),
+			None => (1, is_vowel_at(graphemes, index)),
+		};
+		units.push(Unit {
+			graphemes: index..index + length,
+			is_vowel,
+			has_diaeresis: length == 1 && graphemes[index].nfd().any(|c| c == '\u{308}'),
+			begins_syllable: false,
+		});
+		index += length;
+	}
+	if orthography == Orthography::English {
+		mark_english_nuclei(graphemes, &mut units);
+	}
+	units
+}
+
+/// Drops a silent final `e` from the nuclei, and adds a syllabic final `m`.
+///
+/// The consonant before a syllabic final `le` begins its syllable.
+fn mark_english_nuclei(graphemes: &[&str], units: &mut [Unit]) {
+	let spelling = |unit: &Unit| graphemes[unit.graphemes.clone()].concat().to_lowercase();
+	let has_earlier_nucleus = units.iter().rev().skip(2).any(|unit| unit.is_vowel);
+	let [.., before, previous, last] = units else {
+		return;
+	};
+	if previous.is_vowel || !has_earlier_nucleus {
+		return;
+	}
+	let last_spelling = spelling(last);
+	let previous_spelling = spelling(previous);
+	if last_spelling == "e" {
+		if previous_spelling != "l" || before.is_vowel {
+			last.is_vowel = false;
+		} else {
+			before.begins_syllable = true;
+		}
+	} else if last_spelling == "m" && matches!(previous_spelling.as_str(), "s" | "z" | "th") {
+		last.is_vowel = true;
+	}
+}
+
+fn is_vowel_at(graphemes: &[&str], index: usize) -> bool {
+	match char_type::get_char_type_at(graphemes, index) {
+		CharType::Vowel => true,
+		CharType::Ambiguous => !matches!(
+			char_type::get_char_type_at(graphemes, index + 1),
+			CharType::Vowel
+		),
+		_ => false,
+	}
+}
+
+/// Counts how many units at the end of a consonant cluster form the longest legal onset.
+///
+/// A single consonant is always a legal onset.
+fn longest_onset(graphemes: &[&str], cluster: &[Unit], orthography: Orthography) -> usize {
+	let onsets = orthography.onsets();
+	(2..=cluster.len())
+		.rev()
+		.find(|&length| {
+			let onset = &cluster[cluster.len() - length..];
+			let spelling = graphemes[onset[0].graphemes.start..onset[length - 1].graphemes.end]
+				.concat()
+				.to_lowercase()
+				.nfc()
+				.collect::<String>();
+			onsets.contains(spelling.as_str())
+		})
+		.unwrap_or_else(|| cluster.len().min(1))
+}
+
+#[cfg(test)]
+mod test_syllables {
+	use super::*;
+
+	fn assert_syllables(word: &str, orthography: Orthography, expected: &[&str]) {
+		let texts = syllables(word, orthography)
+			.iter()
+			.map(|syllable| syllable.text)
+			.collect::<Vec<&str>>();
+		assert_eq!(texts, expected);
+	}
+
+	#[test]
+	fn single_syllable() {
+		assert_syllables("a", Orthography::English, &["a"]);
+		assert_syllables("strengths", Orthography::English, &["strengths"]);
+		assert_syllables("hmm", Orthography::English, &["hmm"]);
+	}
+
+	#[test]
+	fn onset_maximization() {
+		assert_syllables("extra", Orthography::English, &["ex", "tra"]);
+		assert_syllables("pastry", Orthography::English, &["pa", "stry"]);
+		assert_syllables("athlete", Orthography::English, &["ath", "lete"]);
+		assert_syllables("Banana", Orthography::Generic, &["Ba", "na", "na"]);
+	}
+
+	#[test]
+	fn silent_e() {
+		assert_syllables("whole", Orthography::English, &["whole"]);
+		assert_syllables("ATHLETE", Orthography::English, &["ATH", "LETE"]);
+		assert_syllables("table", Orthography::English, &["ta", "ble"]);
+		assert_syllables("be", Orthography::English, &["be"]);
+		assert_syllables("agree", Orthography::English, &["a", "gree"]);
+		assert_syllables("athlete", Orthography::Generic, &["ath", "le", "te"]);
+	}
+
+	#[test]
+	fn syllabic_le() {
+		assert_syllables("little", Orthography::English, &["lit", "tle"]);
+		assert_syllables("handle", Orthography::English, &["han", "dle"]);
+		assert_syllables("idle", Orthography::English, &["i", "dle"]);
+		assert_syllables("little", Orthography::Generic, &["litt", "le"]);
+	}
+
+	#[test]
+	fn diaeresis() {
+		assert_syllables("naïve", Orthography::English, &["na", "ïve"]);
+		assert_syllables("nai\u{308}ve", Orthography::English, &["na", "i\u{308}ve"]);
+		assert_syllables("Noël", Orthography::French, &["No", "ël"]);
+		assert_syllables("pingüino", Orthography::Spanish, &["pin", "güi", "no"]);
+		assert_syllables("naive", Orthography::English, &["naive"]);
+	}
+
+	#[test]
+	fn syllabic_consonants() {
+		assert_syllables("prism", Orthography::English, &["pri", "sm"]);
+		assert_syllables("storm", Orthography::English, &["storm"]);
+		assert_syllables("hmm", Orthography::English, &["hmm"]);
+	}
+
+	#[test]
+	fn multigraphs() {
+		assert_syllables("eschew", Orthography::English, &["e", "schew"]);
+		assert_syllables("calle", Orthography::Spanish, &["ca", "lle"]);
+		assert_syllables("calle", Orthography::Generic, &["cal", "le"]);
+		assert_syllables("vrijdag", Orthography::Dutch, &["vrij", "dag"]);
+	}
+
+	#[test]
+	fn y() {
+		assert_syllables("yoyo", Orthography::English, &["yo", "yo"]);
+		assert_syllables("rhythm", Orthography::English, &["rhy", "thm"]);
+		assert_syllables("Tyson", Orthography::English, &["Ty", "son"]);
+	}
+
+	#[test]
+	fn pronounceable() {
+		for word in ["NATO", "laser", "Thrift", "SQUID", "a"] {
+			assert!(is_pronounceable(word, Orthography::English), "{}", word);
+		}
+		for word in ["BBC", "PDF", "ZX", "", "TSKTSK"] {
+			assert!(!is_pronounceable(word, Orthography::English), "{}", word);
+		}
+		assert!(is_pronounceable("PFAD", Orthography::German));
+		assert!(!is_pronounceable("PFAD", Orthography::English));
+	}
+
+	#[test]
+	fn grapheme_ranges() {
+		let syllables = syllables("ženské", Orthography::Czech);
+		let ranges = syllables
+			.iter()
+			.map(|syllable| syllable.graphemes.clone())
+			.collect::<Vec<Range<usize>>>();
+		assert_eq!(ranges, vec![0..3, 3..6]);
+		assert_eq!(syllables[0].text, "žen");
+		assert_eq!(syllables[1].text, "ské");
+	}
+}
@@ -0,0 +1,515 @@
+//! Atomic tokens such as URLs, which are not split into words.
+//!
+//! # Usage
+//!
+//! ```
+//! use porcus::token::TokenPolicy;
+//! use porcus::PigLatinTransformer;
+//!
+//! let transformer = PigLatinTransformer::default().with_token_policy(TokenPolicy::Keep);
+//! assert_eq!(
+//!     transformer.to_pig_latin("See https://example.com/path, @porcus :smile:"),
+//!     "Eesay https://example.com/path, @porcus :smile:"
+//! );
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Kind of an atomic token.
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TokenKind {
+	/// URL with a scheme or starting with `www.`, e.g. `https://example.com/path`.
+	Url,
+	/// Email address, e.g. `me@example.com`.
+	Email,
+	/// Absolute or relative file path, e.g. `/usr/bin`, `C:\Users` or `src/main.rs`.
+	Path,
+	/// Handle, e.g. `@porcus` or `@porcus@example.com`.
+	Handle,
+	/// Hashtag, e.g. `#piglatin`.
+	Hashtag,
+	/// Emoji shortcode, e.g. `:smile:`.
+	Shortcode,
+	/// Version number with at least three parts or a `v`, e.g. `1.2.3` or `v2.0-beta`.
+	Version,
+}
+
+impl fmt::Display for TokenKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Url => "URL",
+			Self::Email => "email",
+			Self::Path => "path",
+			Self::Handle => "handle",
+			Self::Hashtag => "hashtag",
+			Self::Shortcode => "shortcode",
+			Self::Version => "version",
+		})
+	}
+}
+
+/// How atomic tokens are translated.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum TokenPolicy {
+	/// Tokens are split into words like other text, e.g. `https://example.com` becomes
+	/// `httpsay://example.comway`.
+	#[default]
+	Split,
+	/// Tokens are left unchanged.
+	Keep,
+	/// Tokens are left unchanged, except the body of hashtags, e.g. `#bacon` becomes `#aconbay`.
+	TransformHashtags,
+}
+
+impl fmt::Display for TokenPolicy {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Split => "split",
+			Self::Keep => "keep",
+			Self::TransformHashtags => "transform-hashtags",
+		})
+	}
+}
+
+impl TokenPolicy {
+	/// Names of all policies, as accepted by [`TokenPolicy::from_name`].
+	pub const NAMES: &'static [&'static str] = &["split", "keep", "transform-hashtags"];
+
+	/// Gets a policy by name.
+	///
+	/// Returns `None` for unknown names.
+	#[must_use]
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name {
+			"split" => Some(Self::Split),
+			"keep" => Some(Self::Keep),
+			"transform-hashtags" => Some(Self::TransformHashtags),
+			_ => None,
+		}
+	}
+}
+
+/// Splits a string into atomic tokens and the text between them.
+///
+/// Tokens only start after a space or punctuation, and trailing punctuation is left out of URLs
+/// and paths.
+///
+/// ```
+/// # use porcus::token::{split, TokenKind};
+/// assert_eq!(
+///     split("Mail me@example.com (or @me)."),
+///     vec![
+///         ("Mail ", None),
+///         ("me@example.com", Some(TokenKind::Email)),
+///         (" (or ", None),
+///         ("@me", Some(TokenKind::Handle)),
+///         (").", None),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn split(s: &str) -> Vec<(&str, Option<TokenKind>)> {
+	let mut segments = Vec::new();
+	let mut text_start = 0;
+	let mut index = 0;
+	let mut word_end = 0;
+	let mut previous = None;
+	while let Some(c) = s[index..].chars().next() {
+		if word_end <= index {
+			word_end = index + first_word(&s[index..]).len();
+		}
+		if can_start_token(c) && (index == text_start || previous.is_some_and(is_boundary)) {
+			// Tokens have no whitespace, so only the rest of the word is searched.
+			if let Some((length, kind)) = token_at(&s[index..word_end]) {
+				if text_start < index {
+					segments.push((&s[text_start..index], None));
+				}
+				segments.push((&s[index..index + length], Some(kind)));
+				index += length;
+				text_start = index;
+				previous = None;
+				continue;
+			}
+		}
+		previous = Some(c);
+		index += c.len_utf8();
+	}
+	if text_start < s.len() {
+		segments.push((&s[text_start..], None));
+	}
+	segments
+}
+
+/// Finds the length and kind of the token at the start of a string.
+fn token_at(s: &str) -> Option<(usize, TokenKind)> {
+	[
+		(url_length as fn(&str) -> Option<usize>, TokenKind::Url),
+		(email_length, TokenKind::Email),
+		(path_length, TokenKind::Path),
+		(version_length, TokenKind::Version),
+		(handle_length, TokenKind::Handle),
+		(hashtag_length, TokenKind::Hashtag),
+		(shortcode_length, TokenKind::Shortcode),
+	]
+	.into_iter()
+	.find_map(|(length, kind)| length(s).map(|length| (length, kind)))
+}
+
+fn url_length(s: &str) -> Option<usize> {
+	let host_start = if s.len() > 4 && s.as_bytes()[..4].eq_ignore_ascii_case(b"www.") {
+		4
+	} else {
+		let scheme_end = s
+			.find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')))
+			.unwrap_or(s.len());
+		let is_scheme =
+			s.starts_with(|c: char| c.is_ascii_alphabetic()) && s[scheme_end..].starts_with("://");
+		if !is_scheme {
+			return None;
+		}
+		scheme_end + 3
+	};
+	let word = trim_punctuation(s);
+	(word.len() > host_start).then_some(word.len())
+}
+
+fn email_length(s: &str) -> Option<usize> {
+	let local_length = s
+		.find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '%' | '+' | '-')))
+		.unwrap_or(s.len());
+	if local_length == 0 || !s[local_length..].starts_with('@') {
+		return None;
+	}
+	let domain_length = domain_length(&s[local_length + 1..])?;
+	Some(local_length + 1 + domain_length)
+}
+
+/// Finds the length of a domain name of at least two labels, ending with letters.
+fn domain_length(s: &str) -> Option<usize> {
+	let mut length = 0;
+	let mut labels = Vec::new();
+	for label in s.split('.') {
+		let label_length = label
+			.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+			.unwrap_or(label.len());
+		if label_length == 0 {
+			break;
+		}
+		length += label_length + usize::from(!labels.is_empty());
+		labels.push(&label[..label_length]);
+		if label_length < label.len() {
+			break;
+		}
+	}
+	let top_level = labels.last()?;
+	(labels.len() >= 2
+		&& top_level.len() >= 2
+		&& top_level.chars().all(|c| c.is_ascii_alphabetic()))
+	.then_some(length)
+}
+
+fn path_length(s: &str) -> Option<usize> {
+	let bytes = s.as_bytes();
+	let root_length = ["/", "~/", "./", "../", "\\\\", ".\\", "..\\"]
+		.into_iter()
+		.find(|root| s.starts_with(root))
+		.map(str::len)
+		.or_else(|| {
+			(bytes.len() > 2
+				&& bytes[0].is_ascii_alphabetic()
+				&& bytes[1] == b':'
+				&& matches!(bytes[2], b'\\' | b'/'))
+			.then_some(3)
+		});
+	if let Some(root_length) = root_length {
+		let word = trim_punctuation(s);
+		return (word.len() > root_length).then_some(word.len());
+	}
+	// Relative paths need a file extension, so that e.g. `and/or` is not a path.
+	let name_end = s
+		.find(|c: char| !(c.is_alphanumeric() || matches!(c, '/' | '\\' | '.' | '_' | '-')))
+		.unwrap_or(s.len());
+	if !s[name_end..].chars().all(is_trailing_punctuation) {
+		return None;
+	}
+	let word = trim_punctuation(&s[..name_end]);
+	let is_path = word.contains(['/', '\\'])
+		&& word
+			.rsplit(['/', '\\'])
+			.next()
+			.and_then(|name| name.rsplit_once('.'))
+			.is_some_and(|(stem, extension)| {
+				!stem.is_empty() && extension.chars().any(|c| c.is_ascii_alphabetic())
+			});
+	is_path.then_some(word.len())
+}
+
+fn version_length(s: &str) -> Option<usize> {
+	let bytes = s.as_bytes();
+	let prefix_length = usize::from(s.starts_with(['v', 'V']));
+	let digits_at = |index: usize| {
+		bytes[index.min(bytes.len())..]
+			.iter()
+			.take_while(|b| b.is_ascii_digit())
+			.count()
+	};
+	let mut length = prefix_length + digits_at(prefix_length);
+	if length == prefix_length {
+		return None;
+	}
+	let mut dots = 0;
+	while bytes.get(length) == Some(&b'.') && digits_at(length + 1) > 0 {
+		length += 1 + digits_at(length + 1);
+		dots += 1;
+	}
+	if dots + prefix_length < 2 {
+		return None;
+	}
+	if bytes.get(length) == Some(&b'-') {
+		let label = s[length + 1..]
+			.find(|c: char| !(c.is_ascii_alphanumeric() || c == '.'))
+			.map_or(&s[length + 1..], |end| &s[length + 1..length + 1 + end])
+			.trim_end_matches('.');
+		if !label.is_empty() {
+			length += 1 + label.len();
+		}
+	}
+	(!s[length..].starts_with(char::is_alphanumeric)).then_some(length)
+}
+
+fn handle_length(s: &str) -> Option<usize> {
+	let name_length = s.strip_prefix('@').map(name_length)?;
+	if name_length == 0 {
+		return None;
+	}
+	let length = 1 + name_length;
+	// Handles on other servers, e.g. `@porcus@example.com`.
+	let server_length = s[length..]
+		.strip_prefix('@')
+		.and_then(domain_length)
+		.map_or(0, |domain_length| 1 + domain_length);
+	Some(length + server_length)
+}
+
+fn hashtag_length(s: &str) -> Option<usize> {
+	let body = s.strip_prefix('#')?;
+	let body = &body[..name_length(body)];
+	body.chars()
+		.any(char::is_alphabetic)
+		.then_some(1 + body.len())
+}
+
+fn shortcode_length(s: &str) -> Option<usize> {
+	let body = s.strip_prefix(':')?;
+	let body_length = body
+		.find(|c: char| {
+			!(c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '+' | '-'))
+		})
+		.unwrap_or(body.len());
+	(body_length > 0 && body[body_length..].starts_with(':')).then_some(body_length + 2)
+}
+
+fn name_length(s: &str) -> usize {
+	s.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+		.unwrap_or(s.len())
+}
+
+fn first_word(s: &str) -> &str {
+	&s[..s.find(char::is_whitespace).unwrap_or(s.len())]
+}
+
+/// Removes punctuation ending a sentence or closing unopened brackets, e.g.
+/// `(https://example.com).` → `https://example.com`.
+fn trim_punctuation(word: &str) -> &str {
+	const BRACKETS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+	let mut unopened = BRACKETS.map(|(open, close)| {
+		word.chars().filter(|c| *c == close).count() as isize
+			- word.chars().filter(|c| *c == open).count() as isize
+	});
+	let mut end = word.len();
+	for c in word.chars().rev() {
+		if matches!(
+			c,
+			'.' | ',' | ';' | ':' | '!' | '?' | '\'' | '"' | '’' | '”' | '»'
+		) {
+			end -= c.len_utf8();
+			continue;
+		}
+		match BRACKETS.iter().position(|(_, close)| *close == c) {
+			Some(bracket) if unopened[bracket] > 0 => {
+				unopened[bracket] -= 1;
+				end -= c.len_utf8();
+			}
+			_ => break,
+		}
+	}
+	&word[..end]
+}
+
+fn is_sentence_punctuation(c: char) -> bool {
+	matches!(
+		c,
+		'.' | ',' | ';' | ':' | '!' | '?' | '\'' | '"' | '’' | '”' | '»'
+	)
+}
+
+/// Checks whether a character may be trimmed from the end of a token.
+fn is_trailing_punctuation(c: char) -> bool {
+	is_sentence_punctuation(c) || matches!(c, ')' | ']' | '}' | '>')
+}
+
+/// Checks whether a token may start with a character.
+fn can_start_token(c: char) -> bool {
+	c.is_alphanumeric()
+		|| matches!(
+			c,
+			'.' | '_' | '%' | '+' | '-' | '/' | '~' | '\\' | '@' | '#' | ':'
+		)
+}
+
+/// Checks whether a token may start after a character.
+fn is_boundary(previous: char) -> bool {
+	!(previous.is_alphanumeric()
+		|| matches!(
+			previous,
+			'_' | '@' | '#' | ':' | '.' | '/' | '\\' | '-' | '+' | '%' | '~'
+		))
+}
+
+#[cfg(test)]
+mod test_split {
+	use super::*;
+
+	fn tokens(s: &str) -> Vec<(&str, TokenKind)> {
+		split(s)
+			.into_iter()
+			.filter_map(|(segment, kind)| kind.map(|kind| (segment, kind)))
+			.collect()
+	}
+
+	fn assert_token(s: &str, kind: TokenKind) {
+		assert_eq!(tokens(s), vec![(s, kind)], "{}", s);
+	}
+
+	fn assert_no_token(s: &str) {
+		assert_eq!(tokens(s), vec![], "{}", s);
+	}
+
+	#[test]
+	fn urls() {
+		assert_token("https://example.com/path?q=1&r=2#top", TokenKind::Url);
+		assert_token("ftp://example.com", TokenKind::Url);
+		assert_token("www.example.com", TokenKind::Url);
+		assert_eq!(
+			tokens("(see https://en.wikipedia.org/wiki/Pig_Latin_(game)), then"),
+			vec![(
+				"https://en.wikipedia.org/wiki/Pig_Latin_(game)",
+				TokenKind::Url
+			)]
+		);
+		assert_no_token("https://");
+		assert_no_token("example.com");
+	}
+
+	#[test]
+	fn emails() {
+		assert_token("first.last+tag@mail.example.com", TokenKind::Email);
+		assert_eq!(
+			tokens("bob@example.com."),
+			vec![("bob@example.com", TokenKind::Email)]
+		);
+		assert_no_token("bob@localhost");
+		assert_no_token("bob@example.c0m");
+	}
+
+	#[test]
+	fn paths() {
+		assert_token("/usr/local/bin", TokenKind::Path);
+		assert_token("~/.config/porcus.toml", TokenKind::Path);
+		assert_token("../README.md", TokenKind::Path);
+		assert_token("C:\\Users\\Public", TokenKind::Path);
+		assert_token("src/main.rs", TokenKind::Path);
+		assert_no_token("and/or");
+		assert_no_token("a / b");
+	}
+
+	#[test]
+	fn handles() {
+		assert_token("@porcus", TokenKind::Handle);
+		assert_token("@porcus@example.com", TokenKind::Handle);
+		assert_eq!(tokens("@bob's"), vec![("@bob", TokenKind::Handle)]);
+		assert_no_token("@ home");
+	}
+
+	#[test]
+	fn hashtags() {
+		assert_token("#piglatin", TokenKind::Hashtag);
+		assert_token("#café_2024", TokenKind::Hashtag);
+		assert_no_token("#1");
+		assert_no_token("C#");
+	}
+
+	#[test]
+	fn shortcodes() {
+		assert_token(":smile:", TokenKind::Shortcode);
+		assert_token(":+1:", TokenKind::Shortcode);
+		assert_eq!(
+			tokens(":smile::wink:"),
+			vec![
+				(":smile:", TokenKind::Shortcode),
+				(":wink:", TokenKind::Shortcode)
+			]
+		);
+		assert_no_token("Note: this: that");
+		assert_no_token("10:30:00");
+	}
+
+	#[test]
+	fn versions() {
+		assert_token("1.2.3", TokenKind::Version);
+		assert_token("v2.0", TokenKind::Version);
+		assert_token("1.0.0-beta.1", TokenKind::Version);
+		assert_eq!(tokens("v1.2."), vec![("v1.2", TokenKind::Version)]);
+		assert_no_token("3.14");
+		assert_no_token("1.2.3abc");
+		assert_no_token("very");
+	}
+
+	#[test]
+	fn text_is_kept() {
+		let s = "Hi @bob, see v1.2.3 at https://example.com/x.";
+		assert_eq!(
+			split(s)
+				.into_iter()
+				.map(|(segment, _)| segment)
+				.collect::<String>(),
+			s
+		);
+		assert_eq!(split(""), vec![]);
+		assert_eq!(split("no tokens"), vec![("no tokens", None)]);
+	}
+
+	#[test]
+	fn long_punctuation_runs() {
+		// Rescanning the run at each of these characters would take minutes.
+		for s in [
+			")".repeat(200_000),
+			"(".repeat(200_000),
+			"?!".repeat(100_000),
+			format!("https://example.com{}", ")".repeat(200_000)),
+			"a,".repeat(100_000),
+			"a/b,".repeat(50_000),
+		] {
+			assert_eq!(
+				split(&s)
+					.into_iter()
+					.map(|(segment, _)| segment)
+					.collect::<String>(),
+				s
+			);
+		}
+	}
+}
@@ -0,0 +1,355 @@
+//! JavaScript bindings for [porcus](https://github.com/LeopoldTal/porcus/), pig latin for the
+//! whole Latin script.
+//!
+//! Built for `wasm32-unknown-unknown` and bound with `wasm-bindgen`:
+//!
+//! ```js
+//! import init, { PigLatinTransformer, toPigLatin } from "./pkg/porcus_wasm.js";
+//!
+//! await init();
+//! toPigLatin("Pig latin"); // "Igpay atinlay"
+//!
+//! const transformer = new PigLatinTransformer({ vowelSuffix: "yay", dialect: "qu-cluster" });
+//! const { text, spans } = transformer.toPigLatinWithSpans("queen of egg");
+//! ```
+//!
+//! Options take the names of the command-line settings, in camel case. Span offsets are in UTF-16
+//! code units, like JavaScript string indices, so that text nodes can be replaced word by word.
+
+use porcus::acronym::AcronymPolicy;
+use porcus::case::Locale;
+use porcus::compound::{Contraction, Elision, Hyphenation};
+use porcus::dialect::Dialect;
+use porcus::normalization::Normalization;
+use porcus::number::Language;
+use porcus::orthography::Orthography;
+use porcus::token::TokenPolicy;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TYPESCRIPT_TYPES: &str = r#"
+/** Settings of a transformer. Omitted settings take their default values. */
+export interface PigLatinOptions {
+	consonantSuffix?: string;
+	vowelSuffix?: string;
+	dialect?: string;
+	orthography?: string;
+	acronymPolicy?: string;
+	tokenPolicy?: string;
+	normalization?: string;
+	locale?: string;
+	sentenceCapitalization?: boolean;
+	hyphenation?: string;
+	elision?: string;
+	contraction?: string;
+	numbers?: string;
+}
+
+/** Word or separator of a text, mapped to its translation, in UTF-16 code units. */
+export interface Span {
+	inputStart: number;
+	inputEnd: number;
+	outputStart: number;
+	outputEnd: number;
+}
+
+/** Translation of a text, with the spans covering it in order. */
+export interface Translation {
+	text: string;
+	spans: Span[];
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+	#[wasm_bindgen(typescript_type = "PigLatinOptions")]
+	pub type JsOptions;
+
+	#[wasm_bindgen(typescript_type = "Translation")]
+	pub type JsTranslation;
+}
+
+/// Settings of a transformer, by name.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct Options {
+	consonant_suffix: Option<String>,
+	vowel_suffix: Option<String>,
+	dialect: Option<String>,
+	orthography: Option<String>,
+	acronym_policy: Option<String>,
+	token_policy: Option<String>,
+	normalization: Option<String>,
+	locale: Option<String>,
+	sentence_capitalization: Option<bool>,
+	hyphenation: Option<String>,
+	elision: Option<String>,
+	contraction: Option<String>,
+	numbers: Option<String>,
+	/// Settings with other names, which are rejected. JavaScript objects are read field by field,
+	/// so `deny_unknown_fields` would never see them.
+	#[serde(flatten)]
+	unknown: BTreeMap<String, IgnoredAny>,
+}
+
+impl Options {
+	/// Builds a transformer with these settings.
+	///
+	/// Returns an error naming the first unknown setting or setting value.
+	fn to_transformer(&self) -> Result<porcus::PigLatinTransformer, String> {
+		if let Some(name) = self.unknown.keys().next() {
+			return Err(format!("unknown option: {}", name));
+		}
+		let mut transformer = porcus::PigLatinTransformer::default();
+		if let Some(suffix) = &self.consonant_suffix {
+			transformer = transformer.with_consonant_suffix(suffix.as_str());
+		}
+		if let Some(suffix) = &self.vowel_suffix {
+			transformer = transformer.with_vowel_suffix(suffix.as_str());
+		}
+		if let Some(dialect) = parse("dialect", &self.dialect, Dialect::from_preset)? {
+			transformer = transformer.with_dialect(dialect);
+		}
+		if let Some(orthography) = parse("orthography", &self.orthography, Orthography::from_name)?
+		{
+			transformer = transformer.with_orthography(orthography);
+		}
+		if let Some(policy) = parse(
+			"acronym policy",
+			&self.acronym_policy,
+			AcronymPolicy::from_name,
+		)? {
+			transformer = transformer.with_acronym_policy(policy);
+		}
+		if let Some(policy) = parse("token policy", &self.token_policy, TokenPolicy::from_name)? {
+			transformer = transformer.with_token_policy(policy);
+		}
+		if let Some(form) = parse(
+			"normalization",
+			&self.normalization,
+			Normalization::from_name,
+		)? {
+			transformer = transformer.with_normalization(form);
+		}
+		if let Some(locale) = parse("locale", &self.locale, Locale::from_name)? {
+			transformer = transformer.with_locale(locale);
+		}
+		if let Some(enabled) = self.sentence_capitalization {
+			transformer = transformer.with_sentence_capitalization(enabled);
+		}
+		if let Some(hyphenation) = parse("hyphenation", &self.hyphenation, Hyphenation::from_name)?
+		{
+			transformer = transformer.with_hyphenation(hyphenation);
+		}
+		if let Some(elision) = parse("elision", &self.elision, Elision::from_name)? {
+			transformer = transformer.with_elision(elision);
+		}
+		if let Some(contraction) = parse("contraction", &self.contraction, Contraction::from_name)?
+		{
+			transformer = transformer.with_contraction(contraction);
+		}
+		if let Some(language) = parse("numbers", &self.numbers, Language::from_name)? {
+			transformer = transformer.with_numbers(language);
+		}
+		Ok(transformer)
+	}
+}
+
+/// Looks a setting value up by name, if it is set.
+fn parse<T>(
+	setting: &str,
+	name: &Option<String>,
+	from_name: fn(&str) -> Option<T>,
+) -> Result<Option<T>, String> {
+	match name {
+		None => Ok(None),
+		Some(name) => from_name(name)
+			.map(Some)
+			.ok_or_else(|| format!("unknown {}: {}", setting, name)),
+	}
+}
+
+/// Word or separator of a text, mapped to its translation, in UTF-16 code units.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Span {
+	input_start: usize,
+	input_end: usize,
+	output_start: usize,
+	output_end: usize,
+}
+
+/// Translation of a text, with the spans covering it in order.
+#[derive(Debug, Serialize)]
+struct Translation {
+	text: String,
+	spans: Vec<Span>,
+}
+
+impl Translation {
+	/// Translates a text, converting the span offsets from bytes to UTF-16 code units.
+	fn new(transformer: &porcus::PigLatinTransformer, input: &str) -> Self {
+		let (text, byte_spans) = transformer.to_pig_latin_with_spans(input);
+		let mut input_end = 0;
+		let mut output_end = 0;
+		let spans = byte_spans
+			.into_iter()
+			.map(|span| {
+				let input_start = input_end;
+				let output_start = output_end;
+				input_end += utf16_len(&input[span.input]);
+				output_end += utf16_len(&text[span.output]);
+				Span {
+					input_start,
+					input_end,
+					output_start,
+					output_end,
+				}
+			})
+			.collect();
+		Self { text, spans }
+	}
+}
+
+fn utf16_len(s: &str) -> usize {
+	s.chars().map(char::len_utf16).sum()
+}
+
+/// Converter from text to pig latin, with its settings.
+#[wasm_bindgen]
+pub struct PigLatinTransformer {
+	transformer: porcus::PigLatinTransformer,
+}
+
+#[wasm_bindgen]
+impl PigLatinTransformer {
+	/// Creates a transformer. Throws on unknown options or setting values.
+	#[wasm_bindgen(constructor)]
+	pub fn new(options: Option<JsOptions>) -> Result<PigLatinTransformer, JsError> {
+		Ok(Self {
+			transformer: transformer_from_js(options)?,
+		})
+	}
+
+	/// Returns the pig latin translation of a text.
+	#[wasm_bindgen(js_name = toPigLatin)]
+	pub fn to_pig_latin(&self, text: &str) -> String {
+		self.transformer.to_pig_latin(text)
+	}
+
+	/// Returns the pig latin translation of a text, and the spans mapping each word and separator
+	/// of the text to its translation.
+	#[wasm_bindgen(js_name = toPigLatinWithSpans)]
+	pub fn to_pig_latin_with_spans(&self, text: &str) -> Result<JsTranslation, JsError> {
+		let translation = Translation::new(&self.transformer, text);
+		Ok(serde_wasm_bindgen::to_value(&translation)?.unchecked_into())
+	}
+
+	#[wasm_bindgen(js_name = toString)]
+	#[allow(clippy::inherent_to_string)]
+	pub fn to_string(&self) -> String {
+		self.transformer.to_string()
+	}
+}
+
+/// Returns the pig latin translation of a text, with optional settings.
+#[wasm_bindgen(js_name = toPigLatin)]
+pub fn to_pig_latin(text: &str, options: Option<JsOptions>) -> Result<String, JsError> {
+	Ok(transformer_from_js(options)?.to_pig_latin(text))
+}
+
+fn transformer_from_js(options: Option<JsOptions>) -> Result<porcus::PigLatinTransformer, JsError> {
+	let options: Options = match options {
+		Some(options) => serde_wasm_bindgen::from_value(options.into())?,
+		None => Options::default(),
+	};
+	options
+		.to_transformer()
+		.map_err(|message| JsError::new(&message))
+}
+
+#[cfg(test)]
+mod test_options {
+	use super::*;
+
+	#[test]
+	fn defaults() {
+		assert_eq!(
+			Options::default().to_transformer(),
+			Ok(porcus::PigLatinTransformer::default())
+		);
+	}
+
+	#[test]
+	fn settings() {
+		let options = Options {
+			vowel_suffix: Some(String::from("yay")),
+			dialect: Some(String::from("qu-cluster")),
+			sentence_capitalization: Some(true),
+			numbers: Some(String::from("english")),
+			..Options::default()
+		};
+		let transformer = options.to_transformer().unwrap();
+		assert_eq!(transformer.vowel_suffix(), "yay");
+		assert_eq!(
+			transformer.to_pig_latin("Queen of 2 eggs"),
+			"Eenquay ofyay otway eggsyay"
+		);
+	}
+
+	#[test]
+	fn unknown_names() {
+		let options = Options {
+			orthography: Some(String::from("klingon")),
+			..Options::default()
+		};
+		assert_eq!(
+			options.to_transformer(),
+			Err(String::from("unknown orthography: klingon"))
+		);
+	}
+
+	#[test]
+	fn unknown_options() {
+		let options = Options {
+			unknown: BTreeMap::from([(String::from("vowelSufix"), IgnoredAny)]),
+			..Options::default()
+		};
+		assert_eq!(
+			options.to_transformer(),
+			Err(String::from("unknown option: vowelSufix"))
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_spans {
+	use super::*;
+
+	#[test]
+	fn utf16_offsets() {
+		let transformer = porcus::PigLatinTransformer::default();
+		let translation = Translation::new(&transformer, "😀 école 𝔭ig");
+		assert_eq!(translation.text, "😀 écoleway ig𝔭ay");
+		let span = |input_start, input_end, output_start, output_end| Span {
+			input_start,
+			input_end,
+			output_start,
+			output_end,
+		};
+		assert_eq!(
+			translation.spans,
+			vec![
+				span(0, 2, 0, 2),
+				span(2, 3, 2, 3),
+				span(3, 8, 3, 11),
+				span(8, 9, 11, 12),
+				span(9, 13, 12, 18),
+			]
+		);
+	}
+}
@@ -0,0 +1,77 @@
+//! Tests of the bindings in a JavaScript runtime, Node.js by default:
+//!
+//! ```sh
+//! cargo test -p porcus-wasm --target wasm32-unknown-unknown
+//! ```
+//!
+//! The `wasm-bindgen-test-runner` of `wasm-bindgen-cli` must be installed, at the same version as
+//! `wasm-bindgen`.
+
+#![cfg(target_arch = "wasm32")]
+
+use js_sys::{Array, Reflect, JSON};
+use porcus_wasm::{to_pig_latin, JsOptions, PigLatinTransformer};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn options(json: &str) -> Option<JsOptions> {
+	Some(JSON::parse(json).unwrap().unchecked_into())
+}
+
+fn get(object: &JsValue, key: &str) -> JsValue {
+	Reflect::get(object, &JsValue::from_str(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn free_function() {
+	assert_eq!(to_pig_latin("Pig latin", None).unwrap(), "Igpay atinlay");
+	assert_eq!(
+		to_pig_latin(
+			"egg nix",
+			options(r#"{"consonantSuffix": "ey", "vowelSuffix": "yey"}"#)
+		)
+		.unwrap(),
+		"eggyey ixney"
+	);
+}
+
+#[wasm_bindgen_test]
+fn transformer() {
+	let transformer = PigLatinTransformer::new(options(
+		r#"{"vowelSuffix": "yay", "dialect": "qu-cluster"}"#,
+	))
+	.unwrap();
+	assert_eq!(
+		transformer.to_pig_latin("queen of egg"),
+		"eenquay ofyay eggyay"
+	);
+	assert_eq!(
+		transformer.to_string(),
+		"Pig Latin <C+ay V+yay, qu-cluster>"
+	);
+}
+
+#[wasm_bindgen_test]
+fn spans() {
+	let transformer = PigLatinTransformer::new(None).unwrap();
+	let translation: JsValue = transformer
+		.to_pig_latin_with_spans("😀 pig")
+		.unwrap()
+		.into();
+	assert_eq!(get(&translation, "text"), "😀 igpay");
+	let spans = get(&translation, "spans").unchecked_into::<Array>();
+	assert_eq!(spans.length(), 3);
+	let last = spans.get(2);
+	let offsets = ["inputStart", "inputEnd", "outputStart", "outputEnd"]
+		.iter()
+		.map(|key| get(&last, key).as_f64().unwrap())
+		.collect::<Vec<f64>>();
+	assert_eq!(offsets, vec![3.0, 6.0, 3.0, 8.0]);
+}
+
+#[wasm_bindgen_test]
+fn invalid_options() {
+	assert!(PigLatinTransformer::new(options(r#"{"orthography": "klingon"}"#)).is_err());
+	assert!(PigLatinTransformer::new(options(r#"{"vowelSufix": "yay"}"#)).is_err());
+	assert!(PigLatinTransformer::new(options(r#"{"sentenceCapitalization": "yes"}"#)).is_err());
+}